@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_funvec::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+// data
+fn get_map(n: usize) -> BTreeMap<usize, usize> {
+    (0..n).map(|i| (3 * i, 2 * i + 3)).collect()
+}
+fn get_set(n: usize) -> BTreeSet<usize> {
+    (0..n).map(|i| 2 * i).collect()
+}
+
+// variants
+fn use_iter_over(map: &BTreeMap<usize, usize>, set: &BTreeSet<usize>) -> usize {
+    map.iter_over(set.iter().copied()).flatten().sum()
+}
+fn use_iter_over_set(map: &BTreeMap<usize, usize>, set: &BTreeSet<usize>) -> usize {
+    map.iter_over_set(set).map(|(_, x)| x).sum()
+}
+
+fn bench_btreemap_iter_over_set(c: &mut Criterion) {
+    let treatments = vec![100_000, 1_000_000];
+
+    let mut group = c.benchmark_group("funvec_d1_btreemap_iter_over_set");
+
+    for n in &treatments {
+        let map = get_map(*n);
+        let set = get_set(*n);
+
+        group.bench_with_input(BenchmarkId::new("use_iter_over", n), n, |b, _| {
+            b.iter(|| use_iter_over(black_box(&map), black_box(&set)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_iter_over_set", n), n, |b, _| {
+            b.iter(|| use_iter_over_set(black_box(&map), black_box(&set)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_btreemap_iter_over_set);
+criterion_main!(benches);
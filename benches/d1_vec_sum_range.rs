@@ -0,0 +1,47 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_funvec::*;
+
+// data
+fn get_vec_f32(n: usize) -> Vec<f32> {
+    (0..n).map(|i| (i % 17) as f32 * 0.5).collect()
+}
+fn get_vec_i32(n: usize) -> Vec<i32> {
+    (0..n).map(|i| (i % 23) as i32 - 11).collect()
+}
+
+// variants
+fn use_flatten_sum<T: Clone + Copy + std::iter::Sum<T>, F: FunVec<1, T>>(n: usize, vec: &F) -> T {
+    vec.iter_over(0..n).flatten().sum()
+}
+fn use_sum_range<T: Clone + Copy + std::iter::Sum<T>, F: FunVec<1, T>>(n: usize, vec: &F) -> T {
+    vec.sum_range(0..n)
+}
+
+fn bench_sum_range(c: &mut Criterion) {
+    let treatments = vec![1_000_000];
+
+    let mut group = c.benchmark_group("funvec_d1_vec_sum_range");
+
+    for n in &treatments {
+        let vec_f32 = get_vec_f32(*n);
+        let vec_i32 = get_vec_i32(*n);
+
+        group.bench_with_input(BenchmarkId::new("f32_flatten_sum", n), n, |b, n| {
+            b.iter(|| use_flatten_sum(*n, black_box(&vec_f32)))
+        });
+        group.bench_with_input(BenchmarkId::new("f32_sum_range", n), n, |b, n| {
+            b.iter(|| use_sum_range(*n, black_box(&vec_f32)))
+        });
+        group.bench_with_input(BenchmarkId::new("i32_flatten_sum", n), n, |b, n| {
+            b.iter(|| use_flatten_sum(*n, black_box(&vec_i32)))
+        });
+        group.bench_with_input(BenchmarkId::new("i32_sum_range", n), n, |b, n| {
+            b.iter(|| use_sum_range(*n, black_box(&vec_i32)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sum_range);
+criterion_main!(benches);
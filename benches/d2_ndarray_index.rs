@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ndarray::Array2;
+use orx_funvec::*;
+
+// data
+fn get_array(n: usize) -> Array2<usize> {
+    Array2::from_shape_fn((n, n), |(i, j)| i + 2 * j)
+}
+
+// variants
+fn use_tuple_get(n: usize, arr: &Array2<usize>) -> usize {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += arr.get((i, j)).copied().unwrap_or(0);
+        }
+    }
+    sum
+}
+fn use_array_get(n: usize, arr: &Array2<usize>) -> usize {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += arr.get([i, j]).copied().unwrap_or(0);
+        }
+    }
+    sum
+}
+fn use_funvec<V: FunVec<2, usize>>(n: usize, vec: &V) -> usize {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += vec.at((i, j)).unwrap_or(0);
+        }
+    }
+    sum
+}
+
+fn bench_ndarray_index(c: &mut Criterion) {
+    let treatments = vec![1_000];
+
+    let mut group = c.benchmark_group("funvec_d2_ndarray_index");
+
+    for n in &treatments {
+        let arr = get_array(*n);
+        assert_eq!(use_tuple_get(*n, &arr), use_funvec(*n, &arr));
+        assert_eq!(use_array_get(*n, &arr), use_funvec(*n, &arr));
+
+        group.bench_with_input(BenchmarkId::new("use_tuple_get", n), n, |b, n| {
+            b.iter(|| use_tuple_get(*n, black_box(&arr)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_array_get", n), n, |b, n| {
+            b.iter(|| use_array_get(*n, black_box(&arr)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_funvec", n), n, |b, n| {
+            b.iter(|| use_funvec(*n, black_box(&arr)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_ndarray_index);
+criterion_main!(benches);
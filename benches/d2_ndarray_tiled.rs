@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ndarray::Array2;
+use orx_funvec::*;
+
+// data
+fn get_array(n: usize) -> Array2<usize> {
+    Array2::from_shape_fn((n, n), |(i, j)| i + 2 * j)
+}
+
+// variants
+fn use_row_major<V: FunVec<2, usize>>(n: usize, vec: &V) -> usize {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += vec.at((i, j)).unwrap_or(0);
+        }
+    }
+    sum
+}
+fn use_column_major<V: FunVec<2, usize>>(n: usize, vec: &V) -> usize {
+    let mut sum = 0;
+    for j in 0..n {
+        for i in 0..n {
+            sum += vec.at((i, j)).unwrap_or(0);
+        }
+    }
+    sum
+}
+fn use_tiled<V: FunVec<2, usize>>(n: usize, vec: &V, tile: usize) -> usize {
+    vec.iter_tiled(n, n, (tile, tile))
+        .map(|(_, _, x)| x.unwrap_or(0))
+        .sum()
+}
+
+fn bench_ndarray_tiled(c: &mut Criterion) {
+    let treatments = vec![1_000, 4_000];
+
+    let mut group = c.benchmark_group("funvec_d2_ndarray_tiled");
+
+    for n in &treatments {
+        let arr = get_array(*n);
+        assert_eq!(use_row_major(*n, &arr), use_tiled(*n, &arr, 64));
+        assert_eq!(use_column_major(*n, &arr), use_tiled(*n, &arr, 64));
+
+        group.bench_with_input(BenchmarkId::new("use_row_major", n), n, |b, n| {
+            b.iter(|| use_row_major(*n, black_box(&arr)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_column_major", n), n, |b, n| {
+            b.iter(|| use_column_major(*n, black_box(&arr)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_tiled_64", n), n, |b, n| {
+            b.iter(|| use_tiled(*n, black_box(&arr), 64))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_ndarray_tiled);
+criterion_main!(benches);
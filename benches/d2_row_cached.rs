@@ -0,0 +1,56 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_funvec::*;
+use std::collections::HashMap;
+
+// data
+fn get_matrix(n: usize) -> HashMap<usize, Vec<usize>> {
+    (0..n)
+        .map(|i| (i, (0..n).map(|j| i + 2 * j).collect()))
+        .collect()
+}
+
+// variants
+fn use_funvec<V: FunVec<2, usize>>(n: usize, matrix: &V) -> usize {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += matrix.at([i, j]).unwrap_or(0);
+        }
+    }
+    sum
+}
+fn use_row_cached(n: usize, matrix: &HashMap<usize, Vec<usize>>) -> usize {
+    let mut sum = 0;
+    let mut row = RowCached::new(matrix, 0);
+    for _ in 0..n {
+        for j in 0..n {
+            sum += row.at(j).unwrap_or(0);
+        }
+        row.advance();
+    }
+    sum
+}
+
+fn bench_row_cached(c: &mut Criterion) {
+    let treatments = vec![1_000];
+
+    let mut group = c.benchmark_group("funvec_d2_row_cached");
+
+    for n in &treatments {
+        let matrix = get_matrix(*n);
+        assert_eq!(use_funvec(*n, &matrix), use_row_cached(*n, &matrix));
+
+        group.bench_with_input(BenchmarkId::new("use_funvec", n), n, |b, n| {
+            b.iter(|| use_funvec(*n, black_box(&matrix)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_row_cached", n), n, |b, n| {
+            b.iter(|| use_row_cached(*n, black_box(&matrix)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_row_cached);
+criterion_main!(benches);
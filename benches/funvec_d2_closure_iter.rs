@@ -0,0 +1,78 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_closure::Capture;
+use orx_funvec::*;
+
+// data
+fn get_locations(n: usize) -> Vec<(f64, f64)> {
+    (0..n)
+        .map(|i| ((i * 7 % 101) as f64, (i * 13 % 97) as f64))
+        .collect()
+}
+fn get_indices(n: usize) -> Vec<(usize, usize)> {
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, (i * 31 + j * 17) % n)))
+        .collect()
+}
+fn euclidean_distance(location1: (f64, f64), location2: (f64, f64)) -> i32 {
+    let (x1, y1) = location1;
+    let (x2, y2) = location2;
+    ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt() as i32
+}
+fn distance(locations: &[(f64, f64)], i: usize, j: usize) -> Option<i32> {
+    locations
+        .get(i)
+        .and_then(|l1| locations.get(j).map(|l2| euclidean_distance(*l1, *l2)))
+}
+
+// variants
+fn manual_loop(locations: &[(f64, f64)], indices: &[(usize, usize)]) -> i32 {
+    let mut sum = 0;
+    for (i, j) in indices {
+        sum += distance(locations, *i, *j).unwrap_or(0);
+    }
+    sum
+}
+fn use_iter_over<V: FunVec<2, i32>>(costs: &V, indices: &[(usize, usize)]) -> i32 {
+    costs.iter_over(indices.iter().copied()).flatten().sum()
+}
+fn use_at<V: FunVec<2, i32>>(costs: &V, indices: &[(usize, usize)]) -> i32 {
+    let mut sum = 0;
+    for ij in indices {
+        sum += costs.at(*ij).unwrap_or(0);
+    }
+    sum
+}
+
+fn bench_closure_iter(c: &mut Criterion) {
+    let treatments = vec![100, 1_000];
+
+    let mut group = c.benchmark_group("funvec_d2_closure_iter");
+
+    for n in &treatments {
+        let locations = get_locations(*n);
+        let indices = get_indices(*n);
+        let costs = Capture(locations.clone())
+            .fun(|loc, (i, j): (usize, usize)| distance(loc.as_slice(), i, j));
+
+        let expected = manual_loop(&locations, &indices);
+        assert_eq!(expected, use_iter_over(&costs, &indices));
+        assert_eq!(expected, use_at(&costs, &indices));
+
+        group.bench_with_input(BenchmarkId::new("manual_loop", n), n, |b, _| {
+            b.iter(|| manual_loop(black_box(&locations), black_box(&indices)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_iter_over", n), n, |b, _| {
+            b.iter(|| use_iter_over(black_box(&costs), black_box(&indices)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("use_at", n), n, |b, _| {
+            b.iter(|| use_at(black_box(&costs), black_box(&indices)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_closure_iter);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+use ndarray::Array2;
+
+/// A two-dimensional vector zipping two same-shaped `Array2`s, with elements `(a, b)`.
+///
+/// Shapes of the arrays are validated once on construction.
+/// Then, `at((i, j))` returns `Some((a[[i, j]], b[[i, j]]))` for positions within the common shape, and `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use ndarray::Array2;
+///
+/// let costs = Array2::from_elem((2, 2), 10);
+/// let capacities = Array2::from_elem((2, 2), 1.5);
+///
+/// let zipped = ArrayZip2::try_new(&costs, &capacities).unwrap();
+/// assert_eq!(Some((10, 1.5)), zipped.at((1, 0)));
+/// assert_eq!(None, zipped.at((2, 0)));
+///
+/// let mismatch = Array2::from_elem((2, 3), 1.5);
+/// assert!(ArrayZip2::try_new(&costs, &mismatch).is_none());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayZip2<'a, A, B> {
+    a: &'a Array2<A>,
+    b: &'a Array2<B>,
+}
+
+impl<'a, A, B> ArrayZip2<'a, A, B> {
+    /// Zips the two arrays; returns `None` if their shapes differ.
+    pub fn try_new(a: &'a Array2<A>, b: &'a Array2<B>) -> Option<Self> {
+        match a.dim() == b.dim() {
+            true => Some(Self { a, b }),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn arrays(&self) -> (&'a Array2<A>, &'a Array2<B>) {
+        (self.a, self.b)
+    }
+}
+
+/// A two-dimensional vector zipping three same-shaped `Array2`s, with elements `(a, b, c)`.
+///
+/// Shapes of the arrays are validated once on construction.
+/// Then, `at((i, j))` returns `Some((a[[i, j]], b[[i, j]], c[[i, j]]))` for positions within the common shape, and `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use ndarray::Array2;
+///
+/// let a = Array2::from_elem((2, 2), 1);
+/// let b = Array2::from_elem((2, 2), 2);
+/// let c = Array2::from_elem((2, 2), 'c');
+///
+/// let zipped = ArrayZip3::try_new(&a, &b, &c).unwrap();
+/// assert_eq!(Some((1, 2, 'c')), zipped.at((1, 1)));
+/// assert_eq!(None, zipped.at((0, 2)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayZip3<'a, A, B, C> {
+    a: &'a Array2<A>,
+    b: &'a Array2<B>,
+    c: &'a Array2<C>,
+}
+
+impl<'a, A, B, C> ArrayZip3<'a, A, B, C> {
+    /// Zips the three arrays; returns `None` if their shapes differ.
+    pub fn try_new(a: &'a Array2<A>, b: &'a Array2<B>, c: &'a Array2<C>) -> Option<Self> {
+        match a.dim() == b.dim() && a.dim() == c.dim() {
+            true => Some(Self { a, b, c }),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn arrays(&self) -> (&'a Array2<A>, &'a Array2<B>, &'a Array2<C>) {
+        (self.a, self.b, self.c)
+    }
+}
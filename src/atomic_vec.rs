@@ -0,0 +1,28 @@
+use std::sync::atomic::AtomicUsize;
+
+/// A read-only view over a slice of atomic counters as a `FunVec<1, usize>`.
+///
+/// Each `at(i)` call loads the `i`-th atomic with `Ordering::Relaxed`:
+///
+/// * the returned value is a point-in-time snapshot of the counter at the time of the call,
+///   values obtained by different calls are not necessarily consistent with each other;
+/// * relaxed ordering does not synchronize with other memory operations,
+///   which is sufficient for reading counters but not for using them as synchronization flags.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let counters = vec![AtomicUsize::new(3), AtomicUsize::new(7)];
+/// let vec = AtomicVec(&counters);
+///
+/// assert_eq!(Some(7), vec.at(1));
+/// assert_eq!(None, vec.at(2));
+///
+/// counters[1].fetch_add(1, Ordering::Relaxed);
+/// assert_eq!(Some(8), vec.at(1));
+/// ```
+#[derive(Clone, Copy)]
+pub struct AtomicVec<'a>(pub &'a [AtomicUsize]);
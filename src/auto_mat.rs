@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+/// A two-dimensional matrix which is internally represented either densely or sparsely depending on its density.
+///
+/// * `Dense` stores all `rows x cols` cells as a `Vec<Vec<Option<T>>>`, where absent cells are `None`;
+/// * `Sparse` stores only the present cells in a `HashMap<(usize, usize), T>`.
+///
+/// The representation is chosen by `from_entries` at construction: the matrix is dense if the ratio of the present cells to all cells
+/// is at least the given density threshold, and sparse otherwise.
+/// Query results are identical regardless of the internal representation.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let entries = [((0, 0), 1), ((0, 1), 2), ((1, 1), 3)];
+///
+/// let dense = AutoMat::from_entries(2, 2, entries, 0.5);
+/// assert!(dense.is_dense());
+/// assert_eq!(Some(3), dense.at((1, 1)));
+/// assert_eq!(None, dense.at((1, 0)));
+///
+/// let sparse = AutoMat::from_entries(100, 100, entries, 0.5);
+/// assert!(!sparse.is_dense());
+/// assert_eq!(Some(3), sparse.at((1, 1)));
+/// assert_eq!(None, sparse.at((1, 0)));
+/// ```
+#[derive(Clone, Debug)]
+pub enum AutoMat<T> {
+    /// Dense representation storing all cells of the matrix, where absent cells are `None`.
+    Dense(Vec<Vec<Option<T>>>),
+    /// Sparse representation storing only the present cells of the matrix.
+    Sparse(HashMap<(usize, usize), T>),
+}
+
+impl<T> AutoMat<T> {
+    /// Creates a `rows x cols` matrix from the given `entries`, choosing the dense representation
+    /// if the density of the matrix is at least `density_threshold`, and the sparse representation otherwise.
+    ///
+    /// Entries out of the `rows x cols` bounds are ignored; when there exist multiple entries with the same position, the last one wins.
+    /// Matrices whose number of cells does not fit in `usize` are always sparse.
+    pub fn from_entries<I>(rows: usize, cols: usize, entries: I, density_threshold: f64) -> Self
+    where
+        I: IntoIterator<Item = ((usize, usize), T)>,
+    {
+        let map: HashMap<_, _> = entries
+            .into_iter()
+            .filter(|((i, j), _)| *i < rows && *j < cols)
+            .collect();
+
+        let density = match rows.checked_mul(cols) {
+            Some(0) | None => None,
+            Some(num_cells) => Some(map.len() as f64 / num_cells as f64),
+        };
+
+        match density.is_some_and(|density| density >= density_threshold) {
+            true => {
+                let mut dense: Vec<Vec<_>> = (0..rows)
+                    .map(|_| (0..cols).map(|_| None).collect())
+                    .collect();
+                for ((i, j), value) in map {
+                    dense[i][j] = Some(value);
+                }
+                Self::Dense(dense)
+            }
+            false => Self::Sparse(map),
+        }
+    }
+
+    /// Fallible version of `from_entries` which creates the matrix from the given `entries` unless any of them is an error.
+    ///
+    /// Construction short-circuits at the first `Err` entry, which is returned; hence, `?` can be used while loading
+    /// the matrix from a fallible source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let lines = ["0 0 1", "1 1 3"];
+    /// let parse = |line: &str| -> Result<((usize, usize), i32), std::num::ParseIntError> {
+    ///     let mut parts = line.split(' ');
+    ///     let mut next = || parts.next().unwrap_or_default();
+    ///     Ok(((next().parse()?, next().parse()?), next().parse()?))
+    /// };
+    ///
+    /// let matrix = AutoMat::try_from_entries(2, 2, lines.into_iter().map(parse), 0.5).unwrap();
+    /// assert_eq!(Some(3), matrix.at((1, 1)));
+    ///
+    /// let lines = ["0 0 1", "1 x 3"];
+    /// assert!(AutoMat::try_from_entries(2, 2, lines.into_iter().map(parse), 0.5).is_err());
+    /// ```
+    pub fn try_from_entries<I, E>(
+        rows: usize,
+        cols: usize,
+        entries: I,
+        density_threshold: f64,
+    ) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = Result<((usize, usize), T), E>>,
+    {
+        let entries = entries.into_iter().collect::<Result<Vec<_>, E>>()?;
+        Ok(Self::from_entries(rows, cols, entries, density_threshold))
+    }
+
+    /// Returns whether or not the matrix is represented densely.
+    pub fn is_dense(&self) -> bool {
+        matches!(self, Self::Dense(_))
+    }
+
+    /// Returns a reference to the element at the `i`-th row and `j`-th column, if present.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T> {
+        match self {
+            Self::Dense(rows) => rows
+                .get(i)
+                .and_then(|row| row.get(j))
+                .and_then(|x| x.as_ref()),
+            Self::Sparse(map) => map.get(&(i, j)),
+        }
+    }
+}
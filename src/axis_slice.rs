@@ -0,0 +1,62 @@
+use ndarray::Array3;
+
+/// A two-dimensional view over the slice of an `Array3` obtained by fixing the `index` along the `axis`.
+///
+/// `at([i, j])` returns the element of the array at the position where the fixed `axis` takes the value `index`,
+/// and the remaining axes, in their original order, take the values `i` and `j`.
+/// Positions out of the array bounds return `None`; all positions are `None` if `axis` is not one of `0`, `1` or `2`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use ndarray::Array3;
+///
+/// let tensor = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+///
+/// let slice = AxisSlice::new(&tensor, 0, 1);
+/// assert_eq!(Some(123), slice.at([2, 3]));
+///
+/// let slice = AxisSlice::new(&tensor, 2, 3);
+/// assert_eq!(Some(123), slice.at([1, 2]));
+/// assert_eq!(None, slice.at([2, 0]));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct AxisSlice<'a, T> {
+    arr: &'a Array3<T>,
+    axis: usize,
+    index: usize,
+}
+
+impl<'a, T> AxisSlice<'a, T> {
+    /// Returns the position in the underlying array corresponding to the position `[i, j]` of the slice;
+    /// returns `None` if the `axis` is invalid.
+    #[inline(always)]
+    pub(crate) fn position(&self, i: usize, j: usize) -> Option<[usize; 3]> {
+        match self.axis {
+            0 => Some([self.index, i, j]),
+            1 => Some([i, self.index, j]),
+            2 => Some([i, j, self.index]),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying three-dimensional array.
+    pub fn arr(&self) -> &'a Array3<T> {
+        self.arr
+    }
+
+    /// Returns the `[rows, cols]` dimensions of the slice, which are the lengths of the non-fixed axes;
+    /// dimensions are zero if the `axis` is invalid or the `index` is out of bounds along the axis.
+    pub fn dimensions(&self) -> [usize; 2] {
+        let shape = self.arr.shape();
+        match self.axis {
+            axis if axis < 3 && self.index < shape[axis] => {
+                let mut remaining = shape.iter().enumerate().filter(|x| x.0 != axis);
+                let mut next = || remaining.next().map(|x| *x.1).unwrap_or(0);
+                [next(), next()]
+            }
+            _ => [0, 0],
+        }
+    }
+}
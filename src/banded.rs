@@ -0,0 +1,47 @@
+/// A two-dimensional banded matrix storing only its non-zero diagonals.
+///
+/// Each diagonal is represented by its offset from the main diagonal together with its values:
+/// * offset `0` is the main diagonal, positive offsets are the super-diagonals and negative offsets are the sub-diagonals;
+/// * the `k`-th value of the diagonal with offset `d` is the element at `(k, k + d)` if `d >= 0`, and at `(k - d, k)` otherwise;
+///   i.e., values are stored in the order they appear along the diagonal, starting from the top-left.
+///
+/// `at((i, j))` finds the diagonal with offset `j - i` and returns its value at position `min(i, j)`;
+/// `None` if there is no such diagonal or the diagonal is not long enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// // tridiagonal 3x3 matrix
+/// // | 2 -1  . |
+/// // | 1  2 -1 |
+/// // | .  1  2 |
+/// let matrix = Banded::new(vec![(-1, vec![1, 1]), (0, vec![2, 2, 2]), (1, vec![-1, -1])]);
+///
+/// assert_eq!(Some(2), matrix.at((1, 1)));
+/// assert_eq!(Some(-1), matrix.at((1, 2)));
+/// assert_eq!(Some(1), matrix.at((2, 1)));
+/// assert_eq!(None, matrix.at((0, 2)));
+/// ```
+#[derive(derive_new::new, Clone, Debug)]
+pub struct Banded<T> {
+    diagonals: Vec<(isize, Vec<T>)>,
+}
+
+impl<T> Banded<T> {
+    /// Returns the diagonals of the matrix as (offset, values) pairs.
+    pub fn diagonals(&self) -> &[(isize, Vec<T>)] {
+        &self.diagonals
+    }
+
+    /// Returns a reference to the element at `(i, j)`, if it is on one of the stored diagonals.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T> {
+        let offset = j as i128 - i as i128;
+        self.diagonals
+            .iter()
+            .find(|(d, _)| *d as i128 == offset)
+            .and_then(|(_, values)| values.get(i.min(j)))
+    }
+}
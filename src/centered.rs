@@ -0,0 +1,49 @@
+use crate::funvec_val::FunVec;
+
+/// A one-dimensional vector accessed by signed logical indices around an `origin` position of the `inner` vector.
+///
+/// The logical index `i` corresponds to the position `origin + i` of the inner vector;
+/// hence, the logical index `0` is the center and negative indices are to the left of it.
+/// This is convenient for symmetric stencils around a center.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let stencil = Centered::new(vec![1, 4, 6, 4, 1], 2);
+///
+/// assert_eq!(Some(6), stencil.at_signed(0));
+/// assert_eq!(Some(1), stencil.at_signed(-2));
+/// assert_eq!(None, stencil.at_signed(-3));
+/// assert_eq!(None, stencil.at_signed(3));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Centered<V> {
+    inner: V,
+    origin: usize,
+}
+
+impl<V> Centered<V> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the position of the inner vector corresponding to the logical index `0`.
+    pub fn origin(&self) -> usize {
+        self.origin
+    }
+
+    /// Returns the element at the logical index `i`, which is the element at position `origin + i` of the inner vector.
+    ///
+    /// Returns `None` if `origin + i` is negative, or if the inner vector does not have an element at this position.
+    pub fn at_signed<T: Clone + Copy>(&self, i: isize) -> Option<T>
+    where
+        V: FunVec<1, T>,
+    {
+        self.origin
+            .checked_add_signed(i)
+            .and_then(|position| self.inner.at(position))
+    }
+}
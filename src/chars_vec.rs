@@ -0,0 +1,24 @@
+/// A one-dimensional vector of the characters of a string slice, indexed by character position rather than byte position.
+///
+/// `at(i)` returns the `i`-th `char` of the string, or `None` if the string has less than `i + 1` characters.
+/// Since UTF-8 characters have variable widths, the `i`-th character is found by walking the string,
+/// requiring `O(i)` time per access.
+///
+/// For byte access, `&[u8]` obtained by `str::as_bytes` is a `FunVec<1, u8>` with constant time access.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let text = CharsVec("añb");
+///
+/// assert_eq!(Some('ñ'), text.at(1));
+/// assert_eq!(Some('b'), text.at(2));
+/// assert_eq!(None, text.at(3));
+///
+/// let bytes = "añb".as_bytes();
+/// assert_eq!(Some(b'b'), bytes.at(3));
+/// ```
+#[derive(Clone, Copy)]
+pub struct CharsVec<'a>(pub &'a str);
@@ -0,0 +1,68 @@
+use crate::funvec_val::FunVec;
+use std::fmt::Display;
+
+/// Error of the structural validation of a `CheckedMatrix`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The row at the given position is known to be empty, i.e., its bounds is `Some([0])`.
+    EmptyRow {
+        /// Position of the empty row.
+        row: usize,
+    },
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyRow { row } => write!(f, "row {} of the matrix is empty", row),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+/// A two-dimensional matrix of rows which is validated at construction to have no empty rows,
+/// surfacing the structural problems early for algorithms which break on empty rows.
+///
+/// Rows are validated by their `bounds`; a row is rejected if its bounds is `Some([0])`,
+/// while rows with unknown bounds, such as a `HashMap`, are accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let matrix = CheckedMatrix::try_new(vec![vec![1, 2], vec![3]]).unwrap();
+/// assert_eq!(Some(3), matrix.at((1, 0)));
+///
+/// let result = CheckedMatrix::try_new(vec![vec![1, 2], vec![]]);
+/// assert_eq!(Some(MatrixError::EmptyRow { row: 1 }), result.err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CheckedMatrix<V1> {
+    rows: Vec<V1>,
+}
+
+impl<V1> CheckedMatrix<V1> {
+    /// Creates the matrix from the given `rows`; returns `MatrixError::EmptyRow` with the position of the first empty row, if any.
+    pub fn try_new<T>(rows: Vec<V1>) -> Result<Self, MatrixError>
+    where
+        T: Clone + Copy,
+        V1: FunVec<1, T>,
+    {
+        match rows.iter().position(|row| row.bounds() == Some([0])) {
+            Some(row) => Err(MatrixError::EmptyRow { row }),
+            None => Ok(Self { rows }),
+        }
+    }
+
+    /// Returns the rows of the matrix.
+    pub fn rows(&self) -> &[V1] {
+        &self.rows
+    }
+
+    /// Converts the matrix into its rows.
+    pub fn into_rows(self) -> Vec<V1> {
+        self.rows
+    }
+}
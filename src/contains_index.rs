@@ -0,0 +1,39 @@
+use crate::index::FromIndex;
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::Hash,
+};
+
+/// A set of `DIM`-dimensional indices which can answer whether or not it contains an index.
+///
+/// This is implemented for `HashSet<Key>` and `BTreeSet<Key>` where the `Key` can be created from `[usize; DIM]`,
+/// such as `usize`, `(usize, usize)` or `[usize; DIM]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashSet;
+///
+/// let set = HashSet::from_iter([(0, 1), (2, 3)]);
+///
+/// assert!(set.contains_index([2, 3]));
+/// assert!(!set.contains_index([3, 2]));
+/// ```
+pub trait ContainsIndex<const DIM: usize> {
+    /// Returns whether or not the set contains the `index`.
+    fn contains_index(&self, index: [usize; DIM]) -> bool;
+}
+
+impl<const DIM: usize, Key: FromIndex<DIM> + Eq + Hash> ContainsIndex<DIM> for HashSet<Key> {
+    #[inline(always)]
+    fn contains_index(&self, index: [usize; DIM]) -> bool {
+        self.contains(&Key::from_index(index))
+    }
+}
+impl<const DIM: usize, Key: FromIndex<DIM> + Ord> ContainsIndex<DIM> for BTreeSet<Key> {
+    #[inline(always)]
+    fn contains_index(&self, index: [usize; DIM]) -> bool {
+        self.contains(&Key::from_index(index))
+    }
+}
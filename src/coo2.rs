@@ -0,0 +1,72 @@
+/// A two-dimensional sparse matrix in the coordinate (COO) format, a slice of `(i, j, value)` triplets.
+///
+/// `at((i, j))` searches for the triplet with coordinates `(i, j)` and returns its value, or `None` if absent.
+/// When multiple triplets have the same coordinates, the last one wins.
+///
+/// The cost of the search depends on how the matrix is created:
+/// * `Coo2::new` performs a linear search over the triplets, requiring `O(n)` time per access;
+/// * `Coo2::new_sorted` expects the triplets to be sorted by `(i, j)` and performs a binary search, requiring `O(log n)` time per access.
+///   Results are unspecified if the triplets are not sorted.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let triplets = [(0, 1, 1.5), (2, 0, -1.0), (2, 2, 4.0)];
+///
+/// let matrix = Coo2::new(&triplets);
+/// assert_eq!(Some(-1.0), matrix.at((2, 0)));
+/// assert_eq!(None, matrix.at((1, 1)));
+///
+/// let matrix = Coo2::new_sorted(&triplets);
+/// assert_eq!(Some(4.0), matrix.at([2, 2]));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Coo2<'a, T> {
+    triplets: &'a [(usize, usize, T)],
+    sorted: bool,
+}
+
+impl<'a, T> Coo2<'a, T> {
+    /// Creates a matrix over the `triplets` in arbitrary order, which is accessed by linear search.
+    pub fn new(triplets: &'a [(usize, usize, T)]) -> Self {
+        Self {
+            triplets,
+            sorted: false,
+        }
+    }
+
+    /// Creates a matrix over the `triplets` sorted by their `(i, j)` coordinates, which is accessed by binary search.
+    pub fn new_sorted(triplets: &'a [(usize, usize, T)]) -> Self {
+        Self {
+            triplets,
+            sorted: true,
+        }
+    }
+
+    /// Returns whether or not the triplets are expected to be sorted.
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// Returns the value of the last triplet with the coordinates `(i, j)`.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        match self.sorted {
+            true => {
+                let end = self.triplets.partition_point(|t| (t.0, t.1) <= (i, j));
+                match end.checked_sub(1).map(|last| &self.triplets[last]) {
+                    Some(t) if (t.0, t.1) == (i, j) => Some(&t.2),
+                    _ => None,
+                }
+            }
+            false => self
+                .triplets
+                .iter()
+                .rev()
+                .find(|t| (t.0, t.1) == (i, j))
+                .map(|t| &t.2),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/// A two-dimensional sparse matrix over compressed sparse row (CSR) arrays, such as those exported by `FunVec::to_csr`.
+///
+/// The non-empty cells of the `i`-th row are at positions `row_pointers[i]..row_pointers[i + 1]` of `column_indices` and `values`,
+/// where the column indices are sorted within each row.
+/// Hence, `at((i, j))` binary searches the columns of the `i`-th row, costing O(log n) where n is the number of non-empty cells of the row;
+/// it returns `None` if the cell is absent.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let row_pointers = [0, 2, 2, 3];
+/// let column_indices = [0, 2, 1];
+/// let values = [1, 2, 3];
+/// let csr = Csr::new(&row_pointers, &column_indices, &values);
+///
+/// assert_eq!(Some(2), csr.at((0, 2)));
+/// assert_eq!(Some(3), csr.at((2, 1)));
+/// assert_eq!(None, csr.at((1, 1)));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Csr<'a, T> {
+    row_pointers: &'a [usize],
+    column_indices: &'a [usize],
+    values: &'a [T],
+}
+
+impl<'a, T> Csr<'a, T> {
+    /// Returns the value at the `i`-th row and `j`-th column, if present.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        let begin = *self.row_pointers.get(i)?;
+        let end = *self.row_pointers.get(i.checked_add(1)?)?;
+        let columns = self.column_indices.get(begin..end)?;
+        columns
+            .binary_search(&j)
+            .ok()
+            .and_then(|position| self.values.get(begin + position))
+    }
+}
@@ -0,0 +1,30 @@
+/// A one-dimensional vector of the prefix sums of the `inner` vector.
+///
+/// `at(i)` returns the sum of the elements of the inner vector at positions `0..=i`, where holes contribute zero, i.e., `T::default()`.
+/// If the inner vector reports its `bounds`, positions beyond the bounds return `None`.
+///
+/// The prefix sum is computed lazily on each access, which requires `O(i)` time per `at(i)` call.
+/// When all prefix sums are required, the accumulation is better computed once in a single sweep.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let cumulative = Cumulative::new(vec![1, 2, 3, 4]);
+///
+/// assert_eq!(Some(1), cumulative.at(0));
+/// assert_eq!(Some(6), cumulative.at(2));
+/// assert_eq!(None, cumulative.at(4));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Cumulative<V> {
+    inner: V,
+}
+
+impl<V> Cumulative<V> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+}
@@ -0,0 +1,34 @@
+/// A one-dimensional vector wrapping around the first `len` positions of the `inner` vector.
+///
+/// `at(i)` returns `inner.at(i % len)`; hence, the vector is of infinite length and repeats the cycle of the inner vector.
+/// The values are `None` only at the holes of the inner vector within the first `len` positions, or at all positions if `len` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let cyclic = Cyclic::new(vec![10, 20, 30], 3);
+///
+/// assert_eq!(Some(10), cyclic.at(0));
+/// assert_eq!(Some(20), cyclic.at(7));
+/// assert_eq!(vec![30, 10, 20], cyclic.iter_over(5..8).flatten().collect::<Vec<_>>());
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Cyclic<V> {
+    inner: V,
+    len: usize,
+}
+
+impl<V> Cyclic<V> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the position in the inner vector that the given `index` wraps to; `None` if the cycle length is zero.
+    #[inline(always)]
+    pub(crate) fn wrap(&self, index: usize) -> Option<usize> {
+        index.checked_rem(self.len)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{atomic_vec::AtomicVec, funvec_val::FunVec, index::IntoIndex};
+use std::sync::atomic::Ordering;
+
+const DIM: usize = 1;
+
+// val
+impl FunVec<DIM, usize> for AtomicVec<'_> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<usize> {
+        self.0
+            .get(index.into_index()[0])
+            .map(|x| x.load(Ordering::Relaxed))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.0.len()])
+    }
+}
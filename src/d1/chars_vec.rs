@@ -0,0 +1,15 @@
+use crate::{chars_vec::CharsVec, funvec_val::FunVec, index::IntoIndex};
+
+const DIM: usize = 1;
+
+// val
+impl FunVec<DIM, char> for CharsVec<'_> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<char> {
+        self.0.chars().nth(index.into_index()[0])
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.0.chars().count()])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{cumulative::Cumulative, funvec_val::FunVec, index::IntoIndex};
+use std::ops::Add;
+
+const DIM: usize = 1;
+
+// val
+impl<T, V> FunVec<DIM, T> for Cumulative<V>
+where
+    T: Clone + Copy + Add<Output = T> + Default,
+    V: FunVec<DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i] = index.into_index();
+        match self.inner().bounds() {
+            Some([len]) if i >= len => None,
+            _ => Some(self.inner().sum_over_with(0..=i, |_| T::default())),
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.inner().bounds()
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{cyclic::Cyclic, funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy, V: FunVec<DIM, T>> FunVec<DIM, T> for Cyclic<V> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i] = index.into_index();
+        self.wrap(i).and_then(|i| self.inner().at([i]))
+    }
+}
+
+// ref
+impl<T, V: FunVecRef<DIM, T>> FunVecRef<DIM, T> for Cyclic<V> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i] = index.into_index();
+        self.wrap(i).and_then(|i| self.inner().ref_at([i]))
+    }
+}
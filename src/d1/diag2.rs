@@ -0,0 +1,26 @@
+use crate::{diag2::Diag2, funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for Diag2<'_, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i] = index.into_index();
+        self.0.get([i, i]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        let (rows, cols) = self.0.dim();
+        Some([rows.min(cols)])
+    }
+}
+
+// ref
+impl<T> FunVecRef<DIM, T> for Diag2<'_, T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i] = index.into_index();
+        self.0.get([i, i])
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+use generic_array::{ArrayLength, GenericArray};
+
+const DIM: usize = 1;
+
+impl<T: Clone + Copy, N: ArrayLength> FunVec<DIM, T> for GenericArray<T, N> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.get(index.into_index()[0]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
+}
+impl<T, N: ArrayLength> FunVecRef<DIM, T> for GenericArray<T, N> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.get(index.into_index()[0])
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{funvec_val::FunVec, identity_vec::IdentityVec, index::IntoIndex};
+
+const DIM: usize = 1;
+
+// val
+impl FunVec<DIM, usize> for IdentityVec {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<usize> {
+        let [i] = index.into_index();
+        match self.len() {
+            Some(len) if i >= len => None,
+            _ => Some(i),
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.len().map(|len| [len])
+    }
+}
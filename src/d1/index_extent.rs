@@ -0,0 +1,14 @@
+use crate::index_extent::IndexExtent;
+
+const DIM: usize = 1;
+
+impl<T> IndexExtent<DIM> for Vec<T> {
+    fn index_extent(&self) -> Option<([usize; DIM], [usize; DIM])> {
+        self.len().checked_sub(1).map(|last| ([0], [last]))
+    }
+}
+impl<const N: usize, T> IndexExtent<DIM> for [T; N] {
+    fn index_extent(&self) -> Option<([usize; DIM], [usize; DIM])> {
+        N.checked_sub(1).map(|last| ([0], [last]))
+    }
+}
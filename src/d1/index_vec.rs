@@ -0,0 +1,32 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, index_vec::IndexVec};
+use std::ops::Index;
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy, V: Index<usize, Output = T>> FunVec<DIM, T> for IndexVec<V> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i] = index.into_index();
+        match i < self.len() {
+            true => Some(self.inner()[i]),
+            false => None,
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
+}
+
+// ref
+impl<T, V: Index<usize, Output = T>> FunVecRef<DIM, T> for IndexVec<V> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i] = index.into_index();
+        match i < self.len() {
+            true => Some(&self.inner()[i]),
+            false => None,
+        }
+    }
+}
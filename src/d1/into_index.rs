@@ -1,4 +1,7 @@
-use crate::index::{FromIndex, IntoIndex};
+use crate::{
+    index::{saturating_usize, FromIndex, IntoIndex},
+    u_index::UIndex,
+};
 
 const DIM: usize = 1;
 type Tuple = usize;
@@ -16,3 +19,17 @@ impl FromIndex<DIM> for Tuple {
         index[0]
     }
 }
+
+impl IntoIndex<DIM> for UIndex<u32> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        [saturating_usize(self.0)]
+    }
+}
+
+impl IntoIndex<DIM> for UIndex<u64> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        [saturating_usize(self.0)]
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, mmap_vec::MmapVec};
+use bytemuck::Pod;
+
+const DIM: usize = 1;
+
+// val
+impl<T: Pod> FunVec<DIM, T> for MmapVec<'_, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.0.get(index.into_index()[0]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.0.len()])
+    }
+}
+
+// ref
+impl<T: Pod> FunVecRef<DIM, T> for MmapVec<'_, T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.0.get(index.into_index()[0])
+    }
+}
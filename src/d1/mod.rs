@@ -1,9 +1,44 @@
+mod atomic_vec;
+mod chars_vec;
+mod cumulative;
+mod cyclic;
+mod dense_map;
+mod identity_vec;
+mod index_extent;
+mod index_vec;
 mod into_index;
+mod named_vec;
+mod nan_sparse;
+mod nearest;
+mod padded;
+mod pooled;
+mod reversed;
+mod row_cached;
+mod scratch_fun;
+mod slice_view;
+mod sorted_sparse;
 mod std;
+mod stream_vec;
+mod stride1;
+mod sum_axis0;
+mod versioned;
+mod window;
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod diag2;
+
+#[cfg(any(feature = "impl_all", feature = "impl_generic_array"))]
+mod generic_array;
 
 #[cfg(any(feature = "impl_all", feature = "impl_indexmap"))]
 mod indexmap;
 
+#[cfg(any(feature = "impl_all", feature = "impl_mmap"))]
+mod mmap_vec;
+
+#[cfg(any(feature = "impl_all", feature = "impl_nalgebra"))]
+mod nalgebra;
+
 #[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
 mod ndarray;
 
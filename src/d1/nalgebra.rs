@@ -0,0 +1,40 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+use nalgebra::{Point, SVector, Scalar};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Scalar + Copy, const D: usize> FunVec<DIM, T> for Point<T, D> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.coords.get(index.into_index()[0]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([D])
+    }
+}
+impl<T: Scalar + Copy, const D: usize> FunVec<DIM, T> for SVector<T, D> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.get(index.into_index()[0]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([D])
+    }
+}
+
+// ref
+impl<T: Scalar, const D: usize> FunVecRef<DIM, T> for Point<T, D> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.coords.get(index.into_index()[0])
+    }
+}
+impl<T: Scalar, const D: usize> FunVecRef<DIM, T> for SVector<T, D> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.get(index.into_index()[0])
+    }
+}
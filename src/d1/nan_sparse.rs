@@ -0,0 +1,23 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, nan_sparse::NanSparse};
+
+const DIM: usize = 1;
+
+// val
+impl FunVec<DIM, f64> for NanSparse<'_> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<f64> {
+        self.ref_at(index).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.0.len()])
+    }
+}
+
+// ref
+impl FunVecRef<DIM, f64> for NanSparse<'_> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&f64> {
+        self.0.get(index.into_index()[0]).filter(|x| !x.is_nan())
+    }
+}
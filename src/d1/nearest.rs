@@ -0,0 +1,19 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, nearest::Nearest};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for Nearest<'_, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.nearest(index.into_index()[0]).copied()
+    }
+}
+
+// ref
+impl<T> FunVecRef<DIM, T> for Nearest<'_, T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.nearest(index.into_index()[0])
+    }
+}
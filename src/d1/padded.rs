@@ -0,0 +1,31 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, padded::Padded};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy, V: FunVec<DIM, T>> FunVec<DIM, T> for Padded<V, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i] = index.into_index();
+        match i < self.len() {
+            true => self.inner().at([i]).or(Some(*self.pad())),
+            false => None,
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
+}
+
+// ref
+impl<T, V: FunVecRef<DIM, T>> FunVecRef<DIM, T> for Padded<V, T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i] = index.into_index();
+        match i < self.len() {
+            true => self.inner().ref_at([i]).or(Some(self.pad())),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, row_cached::RowCached};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy, V1: FunVec<DIM, T>> FunVec<DIM, T> for RowCached<'_, V1> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.row().and_then(|x| x.at(index))
+    }
+}
+
+// ref
+impl<T, V1: FunVecRef<DIM, T>> FunVecRef<DIM, T> for RowCached<'_, V1> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.row().and_then(|x| x.ref_at(index))
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, scratch_fun::ScratchFun};
+
+const DIM: usize = 1;
+
+// val
+impl<T, F, S> FunVec<DIM, T> for ScratchFun<F, S>
+where
+    T: Clone + Copy,
+    F: Fn(usize, &mut S) -> Option<T>,
+    S: Default + 'static,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.call(index.into_index()[0])
+    }
+}
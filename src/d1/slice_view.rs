@@ -0,0 +1,25 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, slice_view::SliceView};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy, V: FunVec<DIM, T>> FunVec<DIM, T> for SliceView<'_, V> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.position(index.into_index()[0])
+            .and_then(|i| self.inner().at(i))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
+}
+
+// ref
+impl<T, V: FunVecRef<DIM, T>> FunVecRef<DIM, T> for SliceView<'_, V> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.position(index.into_index()[0])
+            .and_then(|i| self.inner().ref_at(i))
+    }
+}
@@ -8,6 +8,10 @@ impl<T: Clone + Copy, A: Array<Item = T>> FunVec<DIM, T> for SmallVec<A> {
     fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
         self.get(index.into_index()[0]).copied()
     }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
 }
 impl<T, A: Array<Item = T>> FunVecRef<DIM, T> for SmallVec<A> {
     #[inline(always)]
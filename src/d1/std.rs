@@ -1,19 +1,69 @@
 use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+use std::{iter::Sum, ops::Range};
 
 const DIM: usize = 1;
 
+#[inline(always)]
+fn sum_clamped<T: Clone + Copy + Sum<T>>(slice: &[T], range: Range<usize>) -> T {
+    let end = range.end.min(slice.len());
+    let begin = range.start.min(end);
+    slice[begin..end].iter().copied().sum()
+}
+
 // val
 impl<T: Clone + Copy> FunVec<DIM, T> for Vec<T> {
     #[inline(always)]
     fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
         self.get(index.into_index()[0]).copied()
     }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
+
+    #[inline(always)]
+    fn sum_range(&self, range: Range<usize>) -> T
+    where
+        T: Sum<T>,
+    {
+        sum_clamped(self, range)
+    }
 }
 impl<const N: usize, T: Clone + Copy> FunVec<DIM, T> for [T; N] {
     #[inline(always)]
     fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
         self.get(index.into_index()[0]).copied()
     }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([N])
+    }
+
+    #[inline(always)]
+    fn sum_range(&self, range: Range<usize>) -> T
+    where
+        T: Sum<T>,
+    {
+        sum_clamped(self, range)
+    }
+}
+impl<T: Clone + Copy> FunVec<DIM, T> for &[T] {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.get(index.into_index()[0]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.len()])
+    }
+
+    #[inline(always)]
+    fn sum_range(&self, range: Range<usize>) -> T
+    where
+        T: Sum<T>,
+    {
+        sum_clamped(self, range)
+    }
 }
 
 // ref
@@ -29,3 +79,9 @@ impl<const N: usize, T> FunVecRef<DIM, T> for [T; N] {
         self.get(index.into_index()[0])
     }
 }
+impl<T> FunVecRef<DIM, T> for &[T] {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.get(index.into_index()[0])
+    }
+}
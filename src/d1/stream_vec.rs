@@ -0,0 +1,15 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, stream_vec::StreamVec};
+
+const DIM: usize = 1;
+
+// val
+impl<T, I> FunVec<DIM, T> for StreamVec<I>
+where
+    T: Clone + Copy,
+    I: Iterator<Item = T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.get(index.into_index()[0])
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, stride1::Stride1};
+
+const DIM: usize = 1;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for Stride1<'_, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.get(index.into_index()[0]).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.len().map(|len| [len])
+    }
+}
+
+// ref
+impl<T> FunVecRef<DIM, T> for Stride1<'_, T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.get(index.into_index()[0])
+    }
+}
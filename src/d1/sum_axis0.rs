@@ -0,0 +1,24 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, sum_axis0::SumAxis0};
+use std::ops::Add;
+
+const DIM: usize = 1;
+const HIGH_DIM: usize = DIM + 1;
+
+// val
+impl<T, V> FunVec<DIM, T> for SumAxis0<V>
+where
+    T: Clone + Copy + Add<Output = T>,
+    V: FunVec<HIGH_DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [j] = index.into_index();
+        (0..self.rows())
+            .filter_map(|i| self.inner().at([i, j]))
+            .reduce(|sum, x| sum + x)
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.inner().bounds().map(|[_, cols]| [cols])
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    array_zip::{ArrayZip2, ArrayZip3},
+    funvec_val::FunVec,
+    index::IntoIndex,
+};
+
+const DIM: usize = 2;
+
+// val
+impl<A: Clone + Copy, B: Clone + Copy> FunVec<DIM, (A, B)> for ArrayZip2<'_, A, B> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<(A, B)> {
+        let index = index.into_index();
+        let (a, b) = self.arrays();
+        a.get(index).and_then(|x| b.get(index).map(|y| (*x, *y)))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.arrays().0.shape().try_into().ok()
+    }
+}
+
+impl<A: Clone + Copy, B: Clone + Copy, C: Clone + Copy> FunVec<DIM, (A, B, C)>
+    for ArrayZip3<'_, A, B, C>
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<(A, B, C)> {
+        let index = index.into_index();
+        let (a, b, c) = self.arrays();
+        a.get(index)
+            .and_then(|x| b.get(index).map(|y| (*x, *y)))
+            .and_then(|(x, y)| c.get(index).map(|z| (x, y, *z)))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.arrays().0.shape().try_into().ok()
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{axis_slice::AxisSlice, funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+
+const DIM: usize = 2;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for AxisSlice<'_, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.position(i, j)
+            .and_then(|position| self.arr().get(position))
+            .copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some(self.dimensions())
+    }
+}
+
+// ref
+impl<T> FunVecRef<DIM, T> for AxisSlice<'_, T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.position(i, j)
+            .and_then(|position| self.arr().get(position))
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{banded::Banded, funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+
+const DIM: usize = 2;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for Banded<T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.get(i, j).copied()
+    }
+}
+
+// ref
+impl<T> FunVecRef<DIM, T> for Banded<T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.get(i, j)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    dense_f64_mat::DenseF64Mat, funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex,
+};
+
+const DIM: usize = 2;
+
+// val
+impl FunVec<DIM, f64> for DenseF64Mat<'_> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<f64> {
+        let [i, j] = index.into_index();
+        self.position(i, j)
+            .and_then(|p| self.data().get(p))
+            .copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some(self.dimensions())
+    }
+}
+
+// ref
+impl FunVecRef<DIM, f64> for DenseF64Mat<'_> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&f64> {
+        let [i, j] = index.into_index();
+        self.position(i, j).and_then(|p| self.data().get(p))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+use generic_array::{ArrayLength, GenericArray};
+
+const DIM: usize = 2;
+const LOW_DIM: usize = DIM - 1;
+
+impl<T: Clone + Copy, V1: FunVec<LOW_DIM, T>, N: ArrayLength> FunVec<DIM, T>
+    for GenericArray<V1, N>
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.get(i).and_then(|x| x.at([j]))
+    }
+}
+impl<T, V1: FunVecRef<LOW_DIM, T>, N: ArrayLength> FunVecRef<DIM, T> for GenericArray<V1, N> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.get(i).and_then(|x| x.ref_at([j]))
+    }
+}
@@ -0,0 +1,16 @@
+use crate::index_extent::{merge_extents, IndexExtent};
+
+const DIM: usize = 2;
+const LOW_DIM: usize = 1;
+
+impl<V1: IndexExtent<LOW_DIM>> IndexExtent<DIM> for Vec<V1> {
+    fn index_extent(&self) -> Option<([usize; DIM], [usize; DIM])> {
+        self.iter()
+            .enumerate()
+            .filter_map(|(i, row)| {
+                row.index_extent()
+                    .map(|([min], [max])| ([i, min], [i, max]))
+            })
+            .fold(None, |extent, row| merge_extents(extent, Some(row)))
+    }
+}
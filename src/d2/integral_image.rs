@@ -0,0 +1,28 @@
+use crate::{
+    funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, integral_image::IntegralImage,
+};
+
+const DIM: usize = 2;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for IntegralImage<T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.get(i, j).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        let (rows, cols) = self.dimensions();
+        Some([rows, cols])
+    }
+}
+
+// ref
+impl<T> FunVecRef<DIM, T> for IntegralImage<T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.get(i, j)
+    }
+}
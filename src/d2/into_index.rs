@@ -1,4 +1,7 @@
-use crate::index::{FromIndex, IntoIndex};
+use crate::{
+    index::{saturating_usize, FromIndex, IntoIndex},
+    u_index::UIndex,
+};
 
 const DIM: usize = 2;
 type Tuple = (usize, usize);
@@ -16,3 +19,31 @@ impl FromIndex<DIM> for Tuple {
         (index[0], index[1])
     }
 }
+
+impl IntoIndex<DIM> for UIndex<(u32, u32)> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        [saturating_usize(self.0 .0), saturating_usize(self.0 .1)]
+    }
+}
+
+impl IntoIndex<DIM> for UIndex<(u64, u64)> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        [saturating_usize(self.0 .0), saturating_usize(self.0 .1)]
+    }
+}
+
+impl IntoIndex<DIM> for UIndex<(u32, usize)> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        [saturating_usize(self.0 .0), self.0 .1]
+    }
+}
+
+impl IntoIndex<DIM> for UIndex<(usize, u32)> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        [self.0 .0, saturating_usize(self.0 .1)]
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{funvec_ref::FunVecRef, iter_all_2d::IterAll2d};
+use std::collections::{BTreeMap, HashMap};
+
+const LOW_DIM: usize = 1;
+
+fn iter_row<'a, T: 'a, V1: AsRef<[T]>>(
+    i: usize,
+    row: &'a V1,
+) -> impl Iterator<Item = (usize, usize, &'a T)> {
+    row.as_ref().iter().enumerate().map(move |(j, x)| (i, j, x))
+}
+
+impl<T, V1: FunVecRef<LOW_DIM, T> + AsRef<[T]>> IterAll2d<T> for Vec<V1> {
+    fn iter_all_2d<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.iter()
+            .enumerate()
+            .flat_map(|(i, row)| iter_row(i, row))
+    }
+}
+impl<T, V1: FunVecRef<LOW_DIM, T> + AsRef<[T]>, const N: usize> IterAll2d<T> for [V1; N] {
+    fn iter_all_2d<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.iter()
+            .enumerate()
+            .flat_map(|(i, row)| iter_row(i, row))
+    }
+}
+impl<T, V1: FunVecRef<LOW_DIM, T> + AsRef<[T]>> IterAll2d<T> for HashMap<usize, V1> {
+    fn iter_all_2d<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        let mut rows: Vec<_> = self.iter().collect();
+        rows.sort_unstable_by_key(|(i, _)| **i);
+        rows.into_iter().flat_map(|(i, row)| iter_row(*i, row))
+    }
+}
+impl<T, V1: FunVecRef<LOW_DIM, T> + AsRef<[T]>> IterAll2d<T> for BTreeMap<usize, V1> {
+    fn iter_all_2d<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.iter().flat_map(|(i, row)| iter_row(*i, row))
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, lazy_rows::LazyRows};
+
+const DIM: usize = 2;
+const LOW_DIM: usize = 1;
+
+// val
+impl<T, R, F> FunVec<DIM, T> for LazyRows<F>
+where
+    T: Clone + Copy,
+    R: FunVec<LOW_DIM, T>,
+    F: Fn(usize) -> Option<R>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        (self.make_row())(i).and_then(|row| row.at(j))
+    }
+}
@@ -1,11 +1,51 @@
+mod auto_mat;
+mod banded;
+mod checked_matrix;
+mod coo2;
+mod csr;
+mod dense_f64_mat;
+mod dict_cols;
+mod index_extent;
 mod into_index;
+mod iter_all_2d;
+mod lazy_rows;
+mod outer_product;
+mod parsed_table;
+mod quad_tree;
+mod ragged_offset;
+mod rectangular;
+mod row_gated;
+mod sparse_rows;
 mod std;
+mod strict;
+mod toroidal2;
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod array_zip;
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod axis_slice;
+
+#[cfg(any(feature = "impl_all", feature = "impl_generic_array"))]
+mod generic_array;
 
 #[cfg(any(feature = "impl_all", feature = "impl_indexmap"))]
 mod indexmap;
 
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod integral_image;
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod masked2;
+
+#[cfg(any(feature = "impl_all", feature = "impl_nalgebra"))]
+mod nalgebra;
+
 #[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
 mod ndarray;
 
+#[cfg(any(feature = "impl_all", feature = "impl_rstar"))]
+mod rstar;
+
 #[cfg(any(feature = "impl_all", feature = "impl_smallvec"))]
 mod smallvec;
@@ -0,0 +1,44 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+use nalgebra::{DMatrix, SMatrix, Scalar};
+
+const DIM: usize = 2;
+
+// val
+impl<T: Scalar + Copy> FunVec<DIM, T> for DMatrix<T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.get((i, j)).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([self.nrows(), self.ncols()])
+    }
+}
+impl<T: Scalar + Copy, const R: usize, const C: usize> FunVec<DIM, T> for SMatrix<T, R, C> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.get((i, j)).copied()
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([R, C])
+    }
+}
+
+// ref
+impl<T: Scalar> FunVecRef<DIM, T> for DMatrix<T> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.get((i, j))
+    }
+}
+impl<T: Scalar, const R: usize, const C: usize> FunVecRef<DIM, T> for SMatrix<T, R, C> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.get((i, j))
+    }
+}
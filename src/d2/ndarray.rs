@@ -1,15 +1,34 @@
 use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
-use ndarray::Array2;
+use ndarray::{ArrayBase, Data, Ix2};
 
 const DIM: usize = 2;
 
-impl<T: Clone + Copy> FunVec<DIM, T> for Array2<T> {
+impl<T, S> FunVec<DIM, T> for ArrayBase<S, Ix2>
+where
+    T: Clone + Copy,
+    S: Data<Elem = T>,
+{
     #[inline(always)]
     fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
         self.get(index.into_index()).copied()
     }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.shape().try_into().ok()
+    }
+
+    fn gather2(&self, indices: &[(usize, usize)]) -> Vec<Option<T>> {
+        let (rows, cols) = self.dim();
+        indices
+            .iter()
+            .map(|(i, j)| match *i < rows && *j < cols {
+                true => Some(self[[*i, *j]]),
+                false => None,
+            })
+            .collect()
+    }
 }
-impl<T> FunVecRef<DIM, T> for Array2<T> {
+impl<T, S: Data<Elem = T>> FunVecRef<DIM, T> for ArrayBase<S, Ix2> {
     #[inline(always)]
     fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
         self.get(index.into_index())
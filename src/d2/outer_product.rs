@@ -0,0 +1,24 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, outer_product::OuterProduct};
+use std::ops::Mul;
+
+const DIM: usize = 2;
+const LOW_DIM: usize = 1;
+
+// val
+impl<T, A, B> FunVec<DIM, T> for OuterProduct<A, B>
+where
+    T: Clone + Copy + Mul<Output = T>,
+    A: FunVec<LOW_DIM, T>,
+    B: FunVec<LOW_DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.a().at(i).and_then(|x| self.b().at(j).map(|y| x * y))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        let ([rows], [cols]) = (self.a().bounds()?, self.b().bounds()?);
+        Some([rows, cols])
+    }
+}
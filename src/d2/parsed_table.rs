@@ -0,0 +1,12 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, parsed_table::ParsedTable};
+
+const DIM: usize = 2;
+
+// val
+impl<T: Clone + Copy> FunVec<DIM, T> for ParsedTable<'_, T> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.get(i, j)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{funvec_val::FunVec, rectangular::Rectangular};
+use std::collections::{BTreeMap, HashMap};
+
+const LOW_DIM: usize = 1;
+
+fn has_cols<T: Clone + Copy, V1: FunVec<LOW_DIM, T>>(row: &V1, expected_cols: usize) -> bool {
+    row.bounds() == Some([expected_cols])
+}
+
+impl<T: Clone + Copy, V1: FunVec<LOW_DIM, T>> Rectangular<T> for Vec<V1> {
+    fn is_rectangular(&self, expected_cols: usize) -> bool {
+        self.iter().all(|row| has_cols(row, expected_cols))
+    }
+}
+impl<T: Clone + Copy, V1: FunVec<LOW_DIM, T>, const N: usize> Rectangular<T> for [V1; N] {
+    fn is_rectangular(&self, expected_cols: usize) -> bool {
+        self.iter().all(|row| has_cols(row, expected_cols))
+    }
+}
+impl<T: Clone + Copy, V1: FunVec<LOW_DIM, T>> Rectangular<T> for HashMap<usize, V1> {
+    fn is_rectangular(&self, expected_cols: usize) -> bool {
+        self.values().all(|row| has_cols(row, expected_cols))
+    }
+}
+impl<T: Clone + Copy, V1: FunVec<LOW_DIM, T>> Rectangular<T> for BTreeMap<usize, V1> {
+    fn is_rectangular(&self, expected_cols: usize) -> bool {
+        self.values().all(|row| has_cols(row, expected_cols))
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, row_gated::RowGated};
+
+const DIM: usize = 2;
+
+// val
+impl<T, V, P> FunVec<DIM, T> for RowGated<V, P>
+where
+    T: Clone + Copy,
+    V: FunVec<DIM, T>,
+    P: Fn(usize) -> bool,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let index = index.into_index();
+        match self.is_active(index[0]) {
+            true => self.inner().at(index),
+            false => None,
+        }
+    }
+}
+
+// ref
+impl<T, V, P> FunVecRef<DIM, T> for RowGated<V, P>
+where
+    V: FunVecRef<DIM, T>,
+    P: Fn(usize) -> bool,
+{
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let index = index.into_index();
+        match self.is_active(index[0]) {
+            true => self.inner().ref_at(index),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, rtree_distances::RTreeDistances};
+
+const DIM: usize = 2;
+
+// val
+impl FunVec<DIM, f64> for RTreeDistances<'_> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<f64> {
+        let [i, j] = index.into_index();
+        self.distance(i, j)
+    }
+}
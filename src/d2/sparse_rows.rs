@@ -0,0 +1,22 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, sparse_rows::SparseRows};
+
+const DIM: usize = 2;
+const LOW_DIM: usize = DIM - 1;
+
+// val
+impl<T: Clone + Copy, V1: FunVec<LOW_DIM, T>> FunVec<DIM, T> for SparseRows<V1> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.row(i).at([j])
+    }
+}
+
+// ref
+impl<T, V1: FunVecRef<LOW_DIM, T>> FunVecRef<DIM, T> for SparseRows<V1> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.row(i).ref_at([j])
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, toroidal2::Toroidal2};
+
+const DIM: usize = 2;
+
+// val
+impl<T: Clone + Copy, V: FunVec<DIM, T>> FunVec<DIM, T> for Toroidal2<V> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let [i, j] = index.into_index();
+        self.wrap(i, j).and_then(|ij| self.inner().at(ij))
+    }
+}
+
+// ref
+impl<T, V: FunVecRef<DIM, T>> FunVecRef<DIM, T> for Toroidal2<V> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let [i, j] = index.into_index();
+        self.wrap(i, j).and_then(|ij| self.inner().ref_at(ij))
+    }
+}
@@ -1,4 +1,5 @@
 mod into_index;
+mod permute3;
 mod std;
 
 #[cfg(any(feature = "impl_all", feature = "impl_indexmap"))]
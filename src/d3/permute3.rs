@@ -0,0 +1,28 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex, permute3::Permute3};
+
+const DIM: usize = 3;
+
+// val
+impl<T: Clone + Copy, V: FunVec<DIM, T>> FunVec<DIM, T> for Permute3<V> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.inner().at(self.permute(index.into_index()))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        let bounds = self.inner().bounds()?;
+        let mut permuted = [0; DIM];
+        for (a, b) in self.perm().into_iter().enumerate() {
+            permuted[b] = bounds[a];
+        }
+        Some(permuted)
+    }
+}
+
+// ref
+impl<T, V: FunVecRef<DIM, T>> FunVecRef<DIM, T> for Permute3<V> {
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        self.inner().ref_at(self.permute(index.into_index()))
+    }
+}
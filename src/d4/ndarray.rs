@@ -1,15 +1,23 @@
 use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
-use ndarray::Array4;
+use ndarray::{ArrayBase, Data, Ix4};
 
 const DIM: usize = 4;
 
-impl<T: Clone + Copy> FunVec<DIM, T> for Array4<T> {
+impl<T, S> FunVec<DIM, T> for ArrayBase<S, Ix4>
+where
+    T: Clone + Copy,
+    S: Data<Elem = T>,
+{
     #[inline(always)]
     fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
         self.get(index.into_index()).copied()
     }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.shape().try_into().ok()
+    }
 }
-impl<T> FunVecRef<DIM, T> for Array4<T> {
+impl<T, S: Data<Elem = T>> FunVecRef<DIM, T> for ArrayBase<S, Ix4> {
     #[inline(always)]
     fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
         self.get(index.into_index())
@@ -0,0 +1,13 @@
+use crate::{dyn_funvec::DynFunVec, funvec_val::FunVec, index::IntoIndex};
+
+// val
+impl<const DIM: usize, T: Clone + Copy + 'static> FunVec<DIM, T> for Box<dyn DynFunVec<DIM, T>> {
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        self.as_ref().dyn_at(index.into_index())
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.as_ref().dyn_bounds()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+use either::Either;
+
+// val
+impl<const DIM: usize, T, L, R> FunVec<DIM, T> for Either<L, R>
+where
+    T: Clone + Copy,
+    L: FunVec<DIM, T>,
+    R: FunVec<DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        match self {
+            Either::Left(x) => x.at(index),
+            Either::Right(x) => x.at(index),
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        match self {
+            Either::Left(x) => x.bounds(),
+            Either::Right(x) => x.bounds(),
+        }
+    }
+}
+
+// ref
+impl<const DIM: usize, T, L, R> FunVecRef<DIM, T> for Either<L, R>
+where
+    L: FunVecRef<DIM, T>,
+    R: FunVecRef<DIM, T>,
+{
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        match self {
+            Either::Left(x) => x.ref_at(index),
+            Either::Right(x) => x.ref_at(index),
+        }
+    }
+}
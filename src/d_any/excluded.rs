@@ -0,0 +1,41 @@
+use crate::{
+    contains_index::ContainsIndex, excluded::Excluded, funvec_ref::FunVecRef, funvec_val::FunVec,
+    index::IntoIndex,
+};
+
+// val
+impl<const DIM: usize, T, V, S> FunVec<DIM, T> for Excluded<V, S>
+where
+    T: Clone + Copy,
+    V: FunVec<DIM, T>,
+    S: ContainsIndex<DIM>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let index = index.into_index();
+        match self.excluded().contains_index(index) {
+            true => None,
+            false => self.inner().at(index),
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.inner().bounds()
+    }
+}
+
+// ref
+impl<const DIM: usize, T, V, S> FunVecRef<DIM, T> for Excluded<V, S>
+where
+    V: FunVecRef<DIM, T>,
+    S: ContainsIndex<DIM>,
+{
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let index = index.into_index();
+        match self.excluded().contains_index(index) {
+            true => None,
+            false => self.inner().ref_at(index),
+        }
+    }
+}
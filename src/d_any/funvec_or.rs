@@ -0,0 +1,35 @@
+use crate::{funvec_or::FunVecOr, funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+
+// val
+impl<const DIM: usize, T, V1, V2> FunVec<DIM, T> for FunVecOr<V1, V2>
+where
+    T: Clone + Copy,
+    V1: FunVec<DIM, T>,
+    V2: FunVec<DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let index = index.into_index();
+        self.first().at(index).or_else(|| self.second().at(index))
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        let (first, second) = (self.first().bounds()?, self.second().bounds()?);
+        Some(core::array::from_fn(|d| first[d].max(second[d])))
+    }
+}
+
+// ref
+impl<const DIM: usize, T, V1, V2> FunVecRef<DIM, T> for FunVecOr<V1, V2>
+where
+    V1: FunVecRef<DIM, T>,
+    V2: FunVecRef<DIM, T>,
+{
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        let index = index.into_index();
+        self.first()
+            .ref_at(index)
+            .or_else(|| self.second().ref_at(index))
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    index::IntoIndex,
+    index_extent::{merge_extents, IndexExtent},
+};
+use std::collections::{BTreeMap, HashMap};
+
+impl<const DIM: usize, Key: IntoIndex<DIM> + Copy, T> IndexExtent<DIM> for HashMap<Key, T> {
+    fn index_extent(&self) -> Option<([usize; DIM], [usize; DIM])> {
+        self.keys()
+            .map(|key| key.into_index())
+            .fold(None, |extent, index| {
+                merge_extents(extent, Some((index, index)))
+            })
+    }
+}
+impl<const DIM: usize, Key: IntoIndex<DIM> + Copy, T> IndexExtent<DIM> for BTreeMap<Key, T> {
+    fn index_extent(&self) -> Option<([usize; DIM], [usize; DIM])> {
+        self.keys()
+            .map(|key| key.into_index())
+            .fold(None, |extent, index| {
+                merge_extents(extent, Some((index, index)))
+            })
+    }
+}
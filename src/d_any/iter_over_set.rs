@@ -0,0 +1,32 @@
+use crate::{index::FromIndex, iter_over_set::IterOverSet};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+impl<const DIM: usize, Key, T> IterOverSet<DIM, Key, T> for BTreeMap<Key, T>
+where
+    Key: FromIndex<DIM> + Ord,
+    T: Clone + Copy,
+{
+    fn iter_over_set<'a>(&'a self, set: &'a BTreeSet<Key>) -> impl Iterator<Item = (&'a Key, T)>
+    where
+        Key: 'a,
+        T: 'a,
+    {
+        let mut entries = self.iter().peekable();
+        let mut indices = set.iter().peekable();
+        std::iter::from_fn(move || loop {
+            let (key, _) = entries.peek()?;
+            let index = indices.peek()?;
+            match key.cmp(index) {
+                Ordering::Less => _ = entries.next(),
+                Ordering::Greater => _ = indices.next(),
+                Ordering::Equal => {
+                    _ = indices.next();
+                    return entries.next().map(|(key, value)| (key, *value));
+                }
+            }
+        })
+    }
+}
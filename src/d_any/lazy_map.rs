@@ -0,0 +1,20 @@
+use crate::{
+    funvec_val::FunVec,
+    index::{FromIndex, IntoIndex},
+    lazy_map::LazyMap,
+};
+use std::hash::Hash;
+
+// val
+impl<const DIM: usize, Key, F, T> FunVec<DIM, T> for LazyMap<Key, F>
+where
+    Key: FromIndex<DIM> + PartialEq + Eq + Hash,
+    F: Fn() -> T,
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let index = Key::from_index(index.into_index());
+        self.0.get(&index).map(|compute| compute())
+    }
+}
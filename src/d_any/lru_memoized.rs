@@ -0,0 +1,27 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, lru_memoized::LruMemoized};
+
+// val
+impl<const DIM: usize, T, V> FunVec<DIM, T> for LruMemoized<DIM, T, V>
+where
+    T: Clone + Copy,
+    V: FunVec<DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        let index = index.into_index();
+        match self.cached(&index) {
+            Some(value) => Some(value),
+            None => {
+                let value = self.inner().at(index);
+                if let Some(value) = value {
+                    self.cache(index, value);
+                }
+                value
+            }
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        self.inner().bounds()
+    }
+}
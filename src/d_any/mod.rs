@@ -1,7 +1,22 @@
 mod box_dyn_fn;
 mod closure;
+mod dyn_funvec;
+mod excluded;
+mod funvec_or;
+mod index_extent;
+mod iter_over_set;
+mod lazy_map;
+mod ref_dyn_fn;
+mod result;
 mod scalars;
 mod std;
+mod transform;
+
+#[cfg(any(feature = "impl_all", feature = "impl_either"))]
+mod either;
 
 #[cfg(any(feature = "impl_all", feature = "impl_indexmap"))]
 mod indexmap;
+
+#[cfg(any(feature = "impl_all", feature = "impl_lru"))]
+mod lru_memoized;
@@ -0,0 +1,37 @@
+use crate::{funvec_ref::FunVecRef, funvec_val::FunVec, index::IntoIndex};
+
+// val
+impl<const DIM: usize, T, V, E> FunVec<DIM, T> for Result<V, E>
+where
+    T: Clone + Copy,
+    V: FunVec<DIM, T>,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<T> {
+        match self {
+            Ok(x) => x.at(index),
+            Err(_) => None,
+        }
+    }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        match self {
+            Ok(x) => x.bounds(),
+            Err(_) => Some([0; DIM]),
+        }
+    }
+}
+
+// ref
+impl<const DIM: usize, T, V, E> FunVecRef<DIM, T> for Result<V, E>
+where
+    V: FunVecRef<DIM, T>,
+{
+    #[inline(always)]
+    fn ref_at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<&T> {
+        match self {
+            Ok(x) => x.ref_at(index),
+            Err(_) => None,
+        }
+    }
+}
@@ -15,6 +15,10 @@ impl<const DIM: usize, T: Clone + Copy> FunVec<DIM, T> for EmptyVec<T> {
     fn at<Idx: IntoIndex<DIM>>(&self, _: Idx) -> Option<T> {
         None
     }
+
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        Some([0; DIM])
+    }
 }
 
 // ref
@@ -0,0 +1,17 @@
+use crate::{funvec_val::FunVec, index::IntoIndex, transform::Transform};
+
+// val
+impl<const DIM: usize, T, U, V, G, H> FunVec<DIM, U> for Transform<V, T, G, H>
+where
+    T: Clone + Copy,
+    U: Clone + Copy,
+    V: FunVec<DIM, T>,
+    G: Fn([usize; DIM]) -> [usize; DIM],
+    H: Fn(T) -> U,
+{
+    #[inline(always)]
+    fn at<Idx: IntoIndex<DIM>>(&self, index: Idx) -> Option<U> {
+        let index = (self.remap())(index.into_index());
+        self.inner().at(index).map(self.map())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::index::checked_index;
+
+/// A two-dimensional view over a flat BLAS-style `&[f64]` buffer with runtime dimensions.
+///
+/// The buffer is interpreted as a `rows` x `cols` matrix either in row-major (C-style) or in column-major (Fortran-style) layout
+/// depending on the `row_major` flag.
+/// Positions out of the `rows` x `cols` bounds, or positions which fall outside of the underlying buffer, return `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+///
+/// let row_major = DenseF64Mat::new(&data, 2, 3, true);
+/// assert_eq!(Some(2.0), row_major.at([0, 1]));
+/// assert_eq!(Some(4.0), row_major.at([1, 0]));
+/// assert_eq!(None, row_major.at([0, 3]));
+///
+/// let col_major = DenseF64Mat::new(&data, 2, 3, false);
+/// assert_eq!(Some(3.0), col_major.at([0, 1]));
+/// assert_eq!(Some(2.0), col_major.at([1, 0]));
+/// assert_eq!(None, col_major.at([2, 0]));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct DenseF64Mat<'a> {
+    data: &'a [f64],
+    rows: usize,
+    cols: usize,
+    row_major: bool,
+}
+
+impl<'a> DenseF64Mat<'a> {
+    /// Returns the position of the element at the `i`-th row and `j`-th column in the flat buffer;
+    /// returns `None` if the index is out of the matrix bounds.
+    #[inline(always)]
+    pub(crate) fn position(&self, i: usize, j: usize) -> Option<usize> {
+        match (i < self.rows, j < self.cols) {
+            (true, true) if self.row_major => checked_index(j, i, self.cols),
+            (true, true) => checked_index(i, j, self.rows),
+            _ => None,
+        }
+    }
+
+    /// Returns the `[rows, cols]` dimensions of the matrix.
+    pub fn dimensions(&self) -> [usize; 2] {
+        [self.rows, self.cols]
+    }
+
+    /// Returns a reference to the underlying flat buffer.
+    pub fn data(&self) -> &'a [f64] {
+        self.data
+    }
+}
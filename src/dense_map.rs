@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+/// A one-dimensional vector over a sparse `HashMap<usize, T>` where absent keys within a length `len` take a `default` value.
+///
+/// * `at(i)` returns the value stored in the `map` for key `i` if `i < len` and the key is present;
+/// * otherwise, returns `Some(default)` if `i < len`;
+/// * and returns `None` for all positions beyond `len`.
+///
+/// This is the map analogue of `Padded`; it has no holes within its bounds and its `bounds` is always `Some([len])`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let map = HashMap::from_iter([(1, 10), (3, 30)]);
+/// let dense = DenseMap::new(&map, 4, 0);
+///
+/// assert_eq!(Some(10), dense.at(1));
+/// assert_eq!(Some(0), dense.at(2));
+/// assert_eq!(None, dense.at(4));
+/// assert_eq!(vec![0, 10, 0, 30], dense.iter_over(0..10).flatten().collect::<Vec<_>>());
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct DenseMap<'a, T> {
+    map: &'a HashMap<usize, T>,
+    len: usize,
+    default: T,
+}
+
+impl<'a, T> DenseMap<'a, T> {
+    /// Returns the value at position `i`, which is the stored value if present or the default value if `i < len`.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&T> {
+        match i < self.len {
+            true => self.map.get(&i).or(Some(&self.default)),
+            false => None,
+        }
+    }
+
+    /// Returns the length of the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the length of the vector is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
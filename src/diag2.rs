@@ -0,0 +1,21 @@
+use ndarray::Array2;
+
+/// A one-dimensional view over the main diagonal of an `Array2`.
+///
+/// `at(i)` returns the element at position `[i, i]` of the array if `i` is within both dimensions of the array; `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use ndarray::Array2;
+///
+/// let matrix = Array2::from_shape_vec((2, 3), vec![1, 2, 3, 4, 5, 6]).unwrap();
+/// let diagonal = Diag2(&matrix);
+///
+/// assert_eq!(Some(1), diagonal.at(0));
+/// assert_eq!(Some(5), diagonal.at(1));
+/// assert_eq!(None, diagonal.at(2));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Diag2<'a, T>(pub &'a Array2<T>);
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+/// A two-dimensional vector where each row stores its elements compactly together with a row-local dictionary
+/// mapping logical columns to storage positions.
+///
+/// `at((i, j))` translates the logical column `j` through the dictionary of the `i`-th row and returns the element at the resulting storage position;
+/// it is `None` if the row does not exist, the column is not in the row's dictionary, or the storage position is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let rows = vec![
+///     (HashMap::from_iter([(3, 0), (7, 1)]), vec![30, 70]),
+///     (HashMap::from_iter([(7, 0)]), vec![700]),
+/// ];
+/// let matrix = DictCols::new(rows);
+///
+/// assert_eq!(Some(70), matrix.at((0, 7)));
+/// assert_eq!(Some(700), matrix.at((1, 7)));
+/// assert_eq!(None, matrix.at((1, 3)));
+/// assert_eq!(None, matrix.at((2, 7)));
+/// ```
+#[derive(derive_new::new, Clone, Debug)]
+pub struct DictCols<T> {
+    rows: Vec<(HashMap<usize, usize>, Vec<T>)>,
+}
+
+impl<T> DictCols<T> {
+    /// Returns a reference to the element at the logical column `j` of the `i`-th row.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.rows
+            .get(i)
+            .and_then(|(dict, values)| dict.get(&j).and_then(|position| values.get(*position)))
+    }
+
+    /// Returns the rows of the matrix as pairs of column dictionaries and stored elements.
+    pub fn rows(&self) -> &[(HashMap<usize, usize>, Vec<T>)] {
+        &self.rows
+    }
+}
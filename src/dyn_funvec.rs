@@ -0,0 +1,71 @@
+use crate::funvec_val::FunVec;
+use std::any::Any;
+
+/// An object-safe counterpart of `FunVec` allowing to store heterogeneous funvecs as trait objects, such as `Vec<Box<dyn DynFunVec<1, T>>>`,
+/// while being able to recover their concrete types.
+///
+/// `DynFunVec` is automatically implemented for all `'static` funvecs, and `Box<dyn DynFunVec<DIM, T>>` in turn implements `FunVec<DIM, T>`.
+///
+/// Rows can be downcast back to their concrete types with `downcast_ref`, which enables hybrid algorithms special-casing, say, dense rows.
+/// Note that `downcast_ref` or `as_any` must be called on the trait object itself rather than on the box,
+/// since the box is also a funvec and hence a `DynFunVec`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let rows: Vec<Box<dyn DynFunVec<1, i32>>> = vec![
+///     Box::new(vec![1, 2, 3]),
+///     Box::new(HashMap::from_iter([(7, 10)])),
+///     Box::new(ScalarAsVec(42)),
+/// ];
+///
+/// assert_eq!(Some(2), rows.at((0, 1)));
+/// assert_eq!(Some(10), rows.at((1, 7)));
+/// assert_eq!(Some(42), rows.at((2, 100)));
+///
+/// let dense: Option<&Vec<i32>> = rows[0].downcast_ref();
+/// assert_eq!(Some(&vec![1, 2, 3]), dense);
+/// assert!(rows[1].downcast_ref::<Vec<i32>>().is_none());
+/// ```
+pub trait DynFunVec<const DIM: usize, T>: Any
+where
+    T: Clone + Copy,
+{
+    /// Returns the value at the given `index` or `None` if the position is empty; see `FunVec::at`.
+    fn dyn_at(&self, index: [usize; DIM]) -> Option<T>;
+
+    /// Returns the exclusive upper bounds of the indices in each dimension; see `FunVec::bounds`.
+    fn dyn_bounds(&self) -> Option<[usize; DIM]>;
+
+    /// Returns the funvec as `&dyn Any` so that it can be downcast to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<const DIM: usize, T, V> DynFunVec<DIM, T> for V
+where
+    T: Clone + Copy,
+    V: FunVec<DIM, T> + Any,
+{
+    #[inline(always)]
+    fn dyn_at(&self, index: [usize; DIM]) -> Option<T> {
+        self.at(index)
+    }
+
+    fn dyn_bounds(&self) -> Option<[usize; DIM]> {
+        self.bounds()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<const DIM: usize, T: Clone + Copy + 'static> dyn DynFunVec<DIM, T> {
+    /// Returns a reference to the concrete funvec if it is of type `V`; `None` otherwise.
+    pub fn downcast_ref<V: Any>(&self) -> Option<&V> {
+        self.as_any().downcast_ref()
+    }
+}
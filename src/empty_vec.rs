@@ -61,4 +61,4 @@ use std::marker::PhantomData;
 /// assert_eq!(None, numbers.at((4, 1, 3, 6))); // array or tuple indices can be used interchangeably
 /// ```
 #[derive(derive_new::new, Default)]
-pub struct EmptyVec<T: ?Sized>(PhantomData<T>);
+pub struct EmptyVec<T: ?Sized>(PhantomData<fn() -> T>);
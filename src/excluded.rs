@@ -0,0 +1,39 @@
+/// A vector which masks the `inner` vector such that the indices in the `excluded` set are missing.
+///
+/// `at(index)` returns `None` if the `excluded` set contains the `index`, regardless of the inner vector; and delegates to the inner vector otherwise.
+/// The excluded set can be any type implementing `ContainsIndex<DIM>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashSet;
+///
+/// let pruned = HashSet::from_iter([1, 3]);
+/// let vec = vec![0, 1, 2, 3].exclude(pruned);
+///
+/// assert_eq!(Some(2), vec.at(2));
+/// assert_eq!(None, vec.at(3));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Excluded<V, S> {
+    inner: V,
+    excluded: S,
+}
+
+impl<V, S> Excluded<V, S> {
+    #[inline(always)]
+    pub(crate) fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns a reference to the set of excluded indices.
+    pub fn excluded(&self) -> &S {
+        &self.excluded
+    }
+
+    /// Returns a mutable reference to the set of excluded indices, which allows to exclude further indices, or to include back.
+    pub fn excluded_mut(&mut self) -> &mut S {
+        &mut self.excluded
+    }
+}
@@ -0,0 +1,62 @@
+/// A vector which returns the element of the `first` vector if present, and the element of the `second` vector otherwise.
+///
+/// This allows to layer an overriding vector on top of a default one, such as sparse overrides over a uniform default.
+/// Several tiers can conveniently be layered by the `funvec_or!` macro.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let overrides = HashMap::from_iter([(1, 10), (3, 30)]);
+/// let vec = FunVecOr::new(overrides, ScalarAsVec(0));
+///
+/// assert_eq!(Some(10), vec.at(1));
+/// assert_eq!(Some(0), vec.at(2));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct FunVecOr<V1, V2> {
+    first: V1,
+    second: V2,
+}
+
+impl<V1, V2> FunVecOr<V1, V2> {
+    #[inline(always)]
+    pub(crate) fn first(&self) -> &V1 {
+        &self.first
+    }
+
+    #[inline(always)]
+    pub(crate) fn second(&self) -> &V2 {
+        &self.second
+    }
+}
+
+/// Layers the given vectors into a single `FunVecOr` chain which returns the element of the first vector in which the position is present.
+///
+/// `funvec_or!(a, b, c, default)` expands into `FunVecOr::new(a, FunVecOr::new(b, FunVecOr::new(c, default)))`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let user = HashMap::from_iter([(0, 1)]);
+/// let project = HashMap::from_iter([(0, 2), (1, 2)]);
+/// let system = HashMap::from_iter([(0, 3), (1, 3), (2, 3)]);
+///
+/// let config = funvec_or!(user, project, system, ScalarAsVec(4));
+///
+/// assert_eq!(vec![1, 2, 3, 4], config.iter_over(0..4).flatten().collect::<Vec<_>>());
+/// ```
+#[macro_export]
+macro_rules! funvec_or {
+    ($vec:expr $(,)?) => {
+        $vec
+    };
+    ($first:expr, $($rest:expr),+ $(,)?) => {
+        $crate::FunVecOr::new($first, $crate::funvec_or!($($rest),+))
+    };
+}
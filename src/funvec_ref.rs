@@ -104,6 +104,20 @@ pub trait FunVecRef<const DIM: usize, T>
 where
     T: ?Sized,
 {
+    /// Number of dimensions of the vector, which is equal to the `DIM` generic parameter.
+    ///
+    /// This allows dimension-generic code to read the dimension at compile time, such as to size buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// assert_eq!(1, <Vec<i32> as FunVecRef<1, i32>>::DIMENSIONS);
+    /// assert_eq!(2, <Vec<Vec<i32>> as FunVecRef<2, i32>>::DIMENSIONS);
+    /// ```
+    const DIMENSIONS: usize = DIM;
+
     /// Returns a reference to the element at the given `index` or `None` if the position is empty.
     ///
     /// This allows to access elements of all funvec implementations in a unified way. Thanks to monomorphization, this abstraction does not have a performance penalty.
@@ -370,7 +384,7 @@ where
     fn ref_iter_over<'a, Idx, IdxIter>(
         &self,
         indices: IdxIter,
-    ) -> IterOverRefs<DIM, T, Idx, IdxIter, Self>
+    ) -> IterOverRefs<'_, DIM, T, Idx, IdxIter, Self>
     where
         Idx: IntoIndex<DIM>,
         IdxIter: Iterator<Item = Idx> + 'a,
@@ -1,4 +1,15 @@
-use crate::{index::IntoIndex, iter_over_val::IterOverValues};
+use crate::{
+    contains_index::ContainsIndex, excluded::Excluded, funvec_or::FunVecOr, index::IntoIndex,
+    iter_over_val::IterOverValues, slice_view::SliceView, sum_axis0::SumAxis0,
+    transform::Transform,
+};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    iter::Sum,
+    ops::{Add, Range, Sub},
+};
 
 /// Trait to provide abstraction over `DIM`-dimensional vectors allowing access using indices.
 ///
@@ -193,6 +204,20 @@ pub trait FunVec<const DIM: usize, T>
 where
     T: Clone + Copy,
 {
+    /// Number of dimensions of the vector, which is equal to the `DIM` generic parameter.
+    ///
+    /// This allows dimension-generic code to read the dimension at compile time, such as to size buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// assert_eq!(1, <Vec<i32> as FunVec<1, i32>>::DIMENSIONS);
+    /// assert_eq!(2, <Vec<Vec<i32>> as FunVec<2, i32>>::DIMENSIONS);
+    /// ```
+    const DIMENSIONS: usize = DIM;
+
     /// Returns the value at the given `index` or `None` if the position is empty.
     ///
     /// This allows to access elements of all funvec implementations in a unified way. Thanks to monomorphization, this abstraction does not have a performance penalty.
@@ -436,11 +461,757 @@ where
     fn iter_over<'a, Idx, IdxIter>(
         &self,
         indices: IdxIter,
-    ) -> IterOverValues<DIM, T, Idx, IdxIter, Self>
+    ) -> IterOverValues<'_, DIM, T, Idx, IdxIter, Self>
     where
         Idx: IntoIndex<DIM>,
         IdxIter: Iterator<Item = Idx> + 'a,
     {
         IterOverValues::new(self, indices)
     }
+
+    /// Returns the sum of the elements of the vector for the given `indices`, where holes contribute `hole(index)` to the sum.
+    ///
+    /// This allows to model missing elements by a value depending on the index,
+    /// such as a penalty for absent edges that depends on the edge, rather than simply skipping them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let costs = HashMap::from_iter([((0, 1), 10), ((1, 2), 20)]);
+    ///
+    /// let penalty = |[i, j]: [usize; 2]| 100 * (i + j) as i32;
+    /// let path = [(0, 1), (1, 2), (2, 3)];
+    ///
+    /// assert_eq!(10 + 20 + 500, costs.sum_over_with(path.iter().copied(), penalty));
+    /// ```
+    fn sum_over_with<Idx, IdxIter, F>(&self, indices: IdxIter, mut hole: F) -> T
+    where
+        Idx: IntoIndex<DIM>,
+        IdxIter: Iterator<Item = Idx>,
+        F: FnMut([usize; DIM]) -> T,
+        T: Add<Output = T> + Default,
+    {
+        indices
+            .map(|i| i.into_index())
+            .fold(T::default(), |sum, index| {
+                sum + self.at(index).unwrap_or_else(|| hole(index))
+            })
+    }
+
+    /// Collects the present elements of the vector for the given `indices` together with their indices,
+    /// sorted by the index; holes are skipped.
+    ///
+    /// The order of the result does not depend on the order of the `indices` or on the underlying storage,
+    /// which allows reproducible outputs from funvecs such as `HashMap` which do not have a deterministic iteration order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let map = HashMap::from_iter([((1, 0), 10), ((0, 2), 20), ((0, 1), 30)]);
+    ///
+    /// let pairs = map.to_sorted_pairs_over([(1, 0), (0, 2), (5, 5), (0, 1)].into_iter());
+    /// assert_eq!(vec![([0, 1], 30), ([0, 2], 20), ([1, 0], 10)], pairs);
+    /// ```
+    fn to_sorted_pairs_over<Idx, IdxIter>(&self, indices: IdxIter) -> Vec<([usize; DIM], T)>
+    where
+        Idx: IntoIndex<DIM>,
+        IdxIter: Iterator<Item = Idx>,
+    {
+        let mut pairs: Vec<_> = indices
+            .map(|i| i.into_index())
+            .filter_map(|index| self.at(index).map(|value| (index, value)))
+            .collect();
+        pairs.sort_by_key(|(index, _)| *index);
+        pairs
+    }
+
+    /// Collects the present elements of the vector for the given `indices` which satisfy the predicate `pred` into a sparse map keyed by their indices;
+    /// holes and elements failing the predicate are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let demands = vec![10, -4, 0, -6];
+    ///
+    /// let supplies = demands.retain_over(0..4, |d| *d > 0);
+    /// assert_eq!(1, supplies.len());
+    /// assert_eq!(Some(&10), supplies.get(&[0]));
+    /// ```
+    fn retain_over<Idx, IdxIter, P>(&self, indices: IdxIter, pred: P) -> HashMap<[usize; DIM], T>
+    where
+        Idx: IntoIndex<DIM>,
+        IdxIter: Iterator<Item = Idx>,
+        P: Fn(&T) -> bool,
+    {
+        indices
+            .map(|i| i.into_index())
+            .filter_map(|index| self.at(index).map(|value| (index, value)))
+            .filter(|(_, value)| pred(value))
+            .collect()
+    }
+
+    /// Returns the exclusive upper bounds of the indices in each dimension, beyond which all elements of the vector are known to be `None`.
+    ///
+    /// Returns `None` when the vector is unbounded, such as `ScalarAsVec`, or when its bounds are not known without inspecting its elements, such as a `HashMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let stdvec = vec![10, 11, 12, 13];
+    /// assert_eq!(Some([4]), stdvec.bounds());
+    ///
+    /// let empty: EmptyVec<i32> = EmptyVec::new();
+    /// assert_eq!(Some([0, 0]), FunVec::<2, _>::bounds(&empty));
+    ///
+    /// let map = HashMap::from_iter([(1, 10), (2, 20)]);
+    /// assert_eq!(None, map.bounds());
+    /// ```
+    fn bounds(&self) -> Option<[usize; DIM]> {
+        None
+    }
+
+    /// Creates a vector which returns the element of this vector if present, and the element of the `fallback` vector otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let overrides = HashMap::from_iter([(1, 10)]);
+    /// let vec = overrides.or(vec![0, 1, 2]);
+    ///
+    /// assert_eq!(Some(0), vec.at(0));
+    /// assert_eq!(Some(10), vec.at(1));
+    /// assert_eq!(None, vec.at(3));
+    /// ```
+    fn or<V: FunVec<DIM, T>>(self, fallback: V) -> FunVecOr<Self, V>
+    where
+        Self: Sized,
+    {
+        FunVecOr::new(self, fallback)
+    }
+
+    /// Renders the `rows` x `cols` top-left block of a two-dimensional vector as a text grid for debugging purposes.
+    ///
+    /// Each row of the vector is rendered on its own line; cells are right-aligned and separated by a space, and holes are rendered as `.`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let matrix = HashMap::from_iter([((0, 0), 1), ((1, 2), 42)]);
+    ///
+    /// assert_eq!(" 1  .  .\n .  . 42", matrix.debug_grid(2, 3));
+    /// ```
+    fn debug_grid(&self, rows: usize, cols: usize) -> String
+    where
+        T: Display,
+        (usize, usize): IntoIndex<DIM>,
+    {
+        let cells: Vec<Vec<String>> = (0..rows)
+            .map(|i| {
+                (0..cols)
+                    .map(|j| match self.at((i, j)) {
+                        Some(value) => value.to_string(),
+                        None => String::from("."),
+                    })
+                    .collect()
+            })
+            .collect();
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|x| x.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let lines: Vec<_> = cells
+            .iter()
+            .map(|row| {
+                let row: Vec<_> = row.iter().map(|x| format!("{:>width$}", x)).collect();
+                row.join(" ")
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Creates a vector which remaps each index by `remap` before accessing this vector, and maps each found value by `map`.
+    ///
+    /// This is equivalent to remapping the indices and mapping the values in two separate steps; however,
+    /// both are fused into a single `Transform` adaptor layer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let vec = vec![1, 2, 3, 4];
+    /// let reversed_squares = vec.transform(|[i]| [3 - i.min(3)], |x| x * x);
+    ///
+    /// assert_eq!(Some(16), reversed_squares.at(0));
+    /// assert_eq!(Some(1), reversed_squares.at(3));
+    /// ```
+    fn transform<U, G, H>(self, remap: G, map: H) -> Transform<Self, T, G, H>
+    where
+        Self: Sized,
+        U: Clone + Copy,
+        G: Fn([usize; DIM]) -> [usize; DIM],
+        H: Fn(T) -> U,
+    {
+        Transform::new(self, remap, map)
+    }
+
+    /// Reduces each row of a two-dimensional vector in the `rows` range into a single value,
+    /// folding the elements at the `cols` columns by `f` starting from `init`; holes are skipped.
+    ///
+    /// Returns one reduced value per row; this is the funvec analogue of summing an `Array2` along its columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let matrix = vec![vec![1, 2, 3], vec![4, 5], vec![]];
+    ///
+    /// let row_sums = matrix.reduce_rows(0..3, 0..3, 0, |x, y| x + y);
+    /// assert_eq!(vec![6, 9, 0], row_sums);
+    ///
+    /// let row_max = matrix.reduce_rows(0..2, [0, 2].into_iter(), i32::MIN, i32::max);
+    /// assert_eq!(vec![3, 4], row_max);
+    /// ```
+    fn reduce_rows<I, F>(&self, rows: Range<usize>, cols: I, init: T, mut f: F) -> Vec<T>
+    where
+        I: Iterator<Item = usize> + Clone,
+        F: FnMut(T, T) -> T,
+        (usize, usize): IntoIndex<DIM>,
+    {
+        rows.map(|i| {
+            cols.clone()
+                .filter_map(|j| self.at((i, j)))
+                .fold(init, &mut f)
+        })
+        .collect()
+    }
+
+    /// Erases the type of the vector by converting it into a boxed closure returning the element at the given index.
+    ///
+    /// This allows to store vectors of different types uniformly; note that the boxed closure itself implements `FunVec<DIM, T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let vectors = vec![
+    ///     vec![1, 2, 3].into_boxed_fn(),
+    ///     HashMap::from_iter([(1, 20)]).into_boxed_fn(),
+    /// ];
+    ///
+    /// assert_eq!(Some(2), vectors[0]([1]));
+    /// assert_eq!(Some(20), vectors[1].at(1));
+    /// assert_eq!(None, vectors[1].at(2));
+    /// ```
+    fn into_boxed_fn(self) -> Box<dyn Fn([usize; DIM]) -> Option<T>>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        Box::new(move |index| self.at(index))
+    }
+
+    /// Reduces the elements of the vector at the given `indices` in parallel by the associative function `f`, starting from the `identity`; holes are skipped.
+    ///
+    /// Iteration and reduction are fused without collecting the elements into an intermediate collection.
+    /// Since the reduction is split across threads, `identity` might be used multiple times and `f` must be associative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let vec: Vec<u64> = (0..1000).collect();
+    ///
+    /// assert_eq!(499500, vec.par_reduce_over(0..2000usize, 0, |x, y| x + y));
+    /// assert_eq!(999, vec.par_reduce_over(0..2000usize, 0, u64::max));
+    /// ```
+    #[cfg(any(feature = "impl_all", feature = "impl_rayon"))]
+    fn par_reduce_over<Idx, I, F>(&self, indices: I, identity: T, f: F) -> T
+    where
+        Self: Sync,
+        T: Send + Sync,
+        Idx: IntoIndex<DIM>,
+        I: rayon::iter::IntoParallelIterator<Item = Idx>,
+        F: Fn(T, T) -> T + Sync + Send,
+    {
+        use rayon::iter::ParallelIterator;
+        indices
+            .into_par_iter()
+            .filter_map(|index| self.at(index))
+            .reduce(|| identity, f)
+    }
+
+    /// Returns the successive differences `at(i + 1) - at(i)` of a one-dimensional vector for each `i` in the `range`.
+    ///
+    /// The difference is `None` if either of the elements at `i` or `i + 1` is a hole.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let series = vec![1, 4, 9, 16];
+    ///
+    /// assert_eq!(vec![Some(3), Some(5), Some(7), None], series.diff_over(0..4));
+    /// ```
+    fn diff_over(&self, range: Range<usize>) -> Vec<Option<T>>
+    where
+        T: Sub<Output = T>,
+        usize: IntoIndex<DIM>,
+    {
+        range
+            .map(
+                |i| match (self.at(i), i.checked_add(1).and_then(|j| self.at(j))) {
+                    (Some(x), Some(y)) => Some(y - x),
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+
+    /// Pools the `rows` x `cols` top-left block of a two-dimensional vector by non-overlapping windows of the given `(height, width)`,
+    /// reducing each window into a single value by `combine`.
+    ///
+    /// The result has `ceil(rows / height)` rows and `ceil(cols / width)` columns, where windows at the bottom and right edges might be partial.
+    /// `combine` is called with the present values of each window, which is empty if all cells of the window are holes.
+    /// The result is empty if either dimension of the window is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let image = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// let max_pooled = image.pool2(2, 3, (2, 2), |x| x.iter().copied().max().unwrap_or(0));
+    /// assert_eq!(vec![vec![5, 6]], max_pooled);
+    /// ```
+    fn pool2<F>(&self, rows: usize, cols: usize, window: (usize, usize), combine: F) -> Vec<Vec<T>>
+    where
+        F: Fn(&[T]) -> T,
+        (usize, usize): IntoIndex<DIM>,
+    {
+        let (height, width) = window;
+        if height == 0 || width == 0 {
+            return vec![];
+        }
+
+        let mut values = vec![];
+        (0..rows.div_ceil(height))
+            .map(|p| {
+                (0..cols.div_ceil(width))
+                    .map(|q| {
+                        values.clear();
+                        for i in (p * height)..rows.min((p + 1).saturating_mul(height)) {
+                            for j in (q * width)..cols.min((q + 1).saturating_mul(width)) {
+                                values.extend(self.at((i, j)));
+                            }
+                        }
+                        combine(&values)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Gathers the elements of a two-dimensional vector at the given `indices`, where holes are `None`.
+    ///
+    /// This is equivalent to collecting `iter_over` over the indices; however, backends such as `ndarray` arrays provide specialized implementations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let matrix = vec![vec![1, 2], vec![3, 4]];
+    ///
+    /// assert_eq!(vec![Some(4), None, Some(2)], matrix.gather2(&[(1, 1), (2, 0), (0, 1)]));
+    /// ```
+    fn gather2(&self, indices: &[(usize, usize)]) -> Vec<Option<T>>
+    where
+        (usize, usize): IntoIndex<DIM>,
+    {
+        indices.iter().map(|ij| self.at(*ij)).collect()
+    }
+
+    /// Creates a vector which returns `None` for the indices contained in the `excluded` set, and the elements of this vector otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let costs = ScalarAsVec(10);
+    /// let pruned = HashSet::from_iter([(0, 1)]);
+    ///
+    /// let costs = costs.exclude(pruned);
+    /// assert_eq!(Some(10), costs.at((1, 0)));
+    /// assert_eq!(None, costs.at((0, 1)));
+    /// ```
+    fn exclude<S: ContainsIndex<DIM>>(self, excluded: S) -> Excluded<Self, S>
+    where
+        Self: Sized,
+    {
+        Excluded::new(self, excluded)
+    }
+
+    /// Returns the present elements of the vector for the given `indices`, in the order of the indices,
+    /// each divided by the total of the present elements; holes are skipped.
+    ///
+    /// Hence, the normalized values sum up to 1, which is useful for probability distributions stored as sparse weights.
+    /// Returns an empty vector if the total is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let weights = HashMap::from_iter([(1, 1.0), (4, 3.0)]);
+    ///
+    /// let probabilities = weights.normalize_over(0..5);
+    /// assert_eq!(vec![(1, 0.25), (4, 0.75)], probabilities);
+    ///
+    /// assert!(weights.normalize_over(5..10).is_empty());
+    /// ```
+    fn normalize_over<Idx, IdxIter>(&self, indices: IdxIter) -> Vec<(Idx, f64)>
+    where
+        Idx: IntoIndex<DIM> + Clone,
+        IdxIter: Iterator<Item = Idx> + Clone,
+        T: Into<f64>,
+    {
+        let total: f64 = indices
+            .clone()
+            .filter_map(|i| self.at(i.into_index()))
+            .map(Into::into)
+            .sum();
+        match total == 0.0 {
+            true => vec![],
+            false => indices
+                .filter_map(|i| {
+                    self.at(i.clone().into_index())
+                        .map(|x| (i, x.into() / total))
+                })
+                .collect(),
+        }
+    }
+
+    /// Visits all cells of the `rows` x `cols` top-left block of a two-dimensional vector tile by tile, yielding `(i, j, value)` triplets,
+    /// where holes are `None`.
+    ///
+    /// Tiles of the given `(height, width)` are visited in row-major order, and the cells within each tile are visited in row-major order;
+    /// tiles at the bottom and right edges might be partial.
+    /// Visiting small blocks at a time improves cache locality for large dense backends such as `ndarray` arrays, particularly when feeding a reduction.
+    /// The iterator is empty if either dimension of the tile is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// let tiled: Vec<_> = matrix.iter_tiled(2, 3, (2, 2)).map(|(_, _, x)| x).collect();
+    /// assert_eq!(vec![Some(1), Some(2), Some(4), Some(5), Some(3), Some(6)], tiled);
+    /// ```
+    fn iter_tiled(
+        &self,
+        rows: usize,
+        cols: usize,
+        tile: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize, Option<T>)> + '_
+    where
+        (usize, usize): IntoIndex<DIM>,
+    {
+        let (height, width) = tile;
+        let (num_tile_rows, num_tile_cols) = match height == 0 || width == 0 {
+            true => (0, 0),
+            false => (rows.div_ceil(height), cols.div_ceil(width)),
+        };
+
+        (0..num_tile_rows)
+            .flat_map(move |p| (0..num_tile_cols).map(move |q| (p, q)))
+            .flat_map(move |(p, q)| {
+                let tile_cols = (q * width)..cols.min((q + 1).saturating_mul(width));
+                ((p * height)..rows.min((p + 1).saturating_mul(height)))
+                    .flat_map(move |i| tile_cols.clone().map(move |j| (i, j)))
+            })
+            .map(move |(i, j)| (i, j, self.at((i, j))))
+    }
+
+    /// Returns an iterator of run-length-encoded `(run_length, value)` pairs of the elements of a one-dimensional vector for the given `indices`,
+    /// coalescing consecutive equal present values into a single run.
+    ///
+    /// Holes break runs: a hole ends the current run and is itself skipped,
+    /// so equal values on both sides of a hole yield two separate runs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let vec = vec![1, 1, 1, 2, 2, 3];
+    /// assert_eq!(vec![(3, 1), (2, 2), (1, 3)], vec.runs_over(0..6).collect::<Vec<_>>());
+    ///
+    /// let sparse = HashMap::from_iter([(0, 7), (1, 7), (3, 7)]);
+    /// assert_eq!(vec![(2, 7), (1, 7)], sparse.runs_over(0..5).collect::<Vec<_>>());
+    /// ```
+    fn runs_over<I>(&self, indices: I) -> impl Iterator<Item = (usize, T)>
+    where
+        I: Iterator<Item = usize>,
+        T: PartialEq,
+        usize: IntoIndex<DIM>,
+    {
+        let mut values = indices.map(|i| self.at(i)).peekable();
+        std::iter::from_fn(move || {
+            let value = values.by_ref().flatten().next()?;
+            let mut run_length = 1;
+            while values.next_if(|next| *next == Some(value)).is_some() {
+                run_length += 1;
+            }
+            Some((run_length, value))
+        })
+    }
+
+    /// Exports the `rows` x `cols` top-left block of a two-dimensional vector into compressed sparse row (CSR) arrays,
+    /// which is the bridge to external sparse solvers.
+    ///
+    /// Returns the `(row_pointers, column_indices, values)` triplet where:
+    /// * `row_pointers` has `rows + 1` elements, and the non-empty cells of the `i`-th row are at positions `row_pointers[i]..row_pointers[i + 1]`
+    ///   of the other two arrays;
+    /// * `column_indices` and `values` hold the column and value of each non-empty cell, sorted by column within each row.
+    ///
+    /// Holes and the cells whose values satisfy `is_empty` are skipped.
+    /// The result can be read back as a funvec by `Csr`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let matrix = vec![vec![1, 0, 2], vec![0, 0, 0], vec![0, 3, 0]];
+    ///
+    /// let (row_pointers, column_indices, values) = matrix.to_csr(3, 3, |x| *x == 0);
+    /// assert_eq!(vec![0, 2, 2, 3], row_pointers);
+    /// assert_eq!(vec![0, 2, 1], column_indices);
+    /// assert_eq!(vec![1, 2, 3], values);
+    /// ```
+    fn to_csr<E>(&self, rows: usize, cols: usize, is_empty: E) -> (Vec<usize>, Vec<usize>, Vec<T>)
+    where
+        E: Fn(&T) -> bool,
+        (usize, usize): IntoIndex<DIM>,
+    {
+        let mut row_pointers = vec![0];
+        let mut column_indices = vec![];
+        let mut values = vec![];
+        for i in 0..rows {
+            for j in 0..cols {
+                if let Some(value) = self.at((i, j)).filter(|x| !is_empty(x)) {
+                    column_indices.push(j);
+                    values.push(value);
+                }
+            }
+            row_pointers.push(values.len());
+        }
+        (row_pointers, column_indices, values)
+    }
+
+    /// Materializes the first `len` elements of a one-dimensional vector into an owned `ndarray::Array1`, filling the holes with `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let sparse = HashMap::from_iter([(1, 10), (3, 30)]);
+    ///
+    /// let dense = sparse.to_array1(4, 0);
+    /// assert_eq!(ndarray::arr1(&[0, 10, 0, 30]), dense);
+    /// ```
+    #[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+    fn to_array1(&self, len: usize, fill: T) -> ndarray::Array1<T>
+    where
+        usize: IntoIndex<DIM>,
+    {
+        ndarray::Array1::from_shape_fn(len, |i| self.at(i).unwrap_or(fill))
+    }
+
+    /// Materializes the `rows` x `cols` top-left block of a two-dimensional vector into an owned `ndarray::Array2`, filling the holes with `fill`.
+    ///
+    /// This bridges back to `ndarray`, such as for BLAS operations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let sparse = HashMap::from_iter([((0, 1), 1.5), ((1, 0), 2.5)]);
+    ///
+    /// let dense = sparse.to_array2(2, 2, 0.0);
+    /// assert_eq!(ndarray::arr2(&[[0.0, 1.5], [2.5, 0.0]]), dense);
+    /// ```
+    #[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+    fn to_array2(&self, rows: usize, cols: usize, fill: T) -> ndarray::Array2<T>
+    where
+        (usize, usize): IntoIndex<DIM>,
+    {
+        ndarray::Array2::from_shape_fn((rows, cols), |ij| self.at(ij).unwrap_or(fill))
+    }
+
+    /// Returns a bounded and rebased view over the `range` of a one-dimensional vector,
+    /// where index `0` of the view corresponds to `range.start` and indices `>= range.len()` are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let vec = vec![0, 10, 20, 30, 40];
+    /// let view = vec.slice_vec(2..5);
+    ///
+    /// assert_eq!(vec![20, 30, 40], view.iter_over(0..10).flatten().collect::<Vec<_>>());
+    /// ```
+    fn slice_vec(&self, range: Range<usize>) -> SliceView<'_, Self>
+    where
+        Self: Sized,
+        usize: IntoIndex<DIM>,
+    {
+        SliceView::new(self, range)
+    }
+
+    /// Returns a lazy one-dimensional vector whose `j`-th element is the sum of the `j`-th column of this two-dimensional vector
+    /// over the first `rows` rows; holes are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let sparse = HashMap::from_iter([((0, 1), 10), ((2, 1), 5), ((1, 0), 3)]);
+    /// let column_sums = sparse.sum_axis0(3);
+    ///
+    /// assert_eq!(Some(3), column_sums.at(0));
+    /// assert_eq!(Some(15), column_sums.at(1));
+    /// assert_eq!(None, column_sums.at(2));
+    /// ```
+    fn sum_axis0(self, rows: usize) -> SumAxis0<Self>
+    where
+        Self: Sized,
+        (usize, usize): IntoIndex<DIM>,
+    {
+        SumAxis0::new(self, rows)
+    }
+
+    /// Returns the sum of the elements at positions within the `range` of this one-dimensional vector; holes are skipped.
+    ///
+    /// The default implementation accesses the positions one by one; dense implementations such as `Vec<T>`, `[T; N]` and `&[T]`
+    /// override it to sum the underlying slice directly after clamping the `range` to their length, which allows auto-vectorization.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let dense = vec![1, 2, 3, 4, 5];
+    /// assert_eq!(9, dense.sum_range(1..4));
+    /// assert_eq!(12, dense.sum_range(2..100));
+    ///
+    /// let sparse = HashMap::from_iter([(1, 10), (7, 5)]);
+    /// assert_eq!(10, sparse.sum_range(0..5));
+    /// ```
+    fn sum_range(&self, range: Range<usize>) -> T
+    where
+        T: Sum<T>,
+        usize: IntoIndex<DIM>,
+    {
+        range.filter_map(|i| self.at(i)).sum()
+    }
+
+    /// Aggregates the rows within `rows` of this two-dimensional vector sharing the same key computed by `key` from the row index,
+    /// restricted to the columns within `cols`.
+    ///
+    /// Cells of rows sharing a key are combined elementwise with `combine`, such as `|a, b| a + b` to sum the rows;
+    /// holes are skipped, and a cell which is a hole in all rows of a group is `T::default()`.
+    /// The `k`-th element of each aggregated row corresponds to column `cols.start + k`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_funvec::*;
+    ///
+    /// let matrix = vec![vec![1, 2], vec![10, 20], vec![3, 4], vec![30]];
+    ///
+    /// let parity = matrix.group_rows_by(0..4, 0..2, |i| i % 2, |a, b| a + b);
+    /// assert_eq!(2, parity.len());
+    /// assert_eq!(Some(&vec![4, 6]), parity.get(&0));
+    /// assert_eq!(Some(&vec![40, 20]), parity.get(&1));
+    /// ```
+    fn group_rows_by<K, F, G>(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+        key: F,
+        combine: G,
+    ) -> HashMap<K, Vec<T>>
+    where
+        K: Eq + Hash,
+        F: Fn(usize) -> K,
+        G: Fn(T, T) -> T,
+        T: Default,
+        (usize, usize): IntoIndex<DIM>,
+    {
+        let mut groups: HashMap<K, Vec<Option<T>>> = HashMap::new();
+        for i in rows {
+            let group = groups
+                .entry(key(i))
+                .or_insert_with(|| vec![None; cols.len()]);
+            for (cell, j) in group.iter_mut().zip(cols.clone()) {
+                if let Some(value) = self.at((i, j)) {
+                    *cell = Some(match *cell {
+                        Some(current) => combine(current, value),
+                        None => value,
+                    });
+                }
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(k, group)| {
+                (
+                    k,
+                    group.into_iter().map(Option::unwrap_or_default).collect(),
+                )
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,39 @@
+/// A one-dimensional vector whose value at each position is the position itself, `at(i) == Some(i)`.
+///
+/// The vector is of infinite length when created by `IdentityVec::new()`.
+/// Alternatively, it can be created by `IdentityVec::bounded(len)` in which case positions beyond `len` return `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let identity = IdentityVec::new();
+/// assert_eq!(Some(42), identity.at(42));
+/// assert_eq!(vec![0, 1, 2], identity.iter_over(0..3).flatten().collect::<Vec<_>>());
+///
+/// let bounded = IdentityVec::bounded(3);
+/// assert_eq!(Some(2), bounded.at(2));
+/// assert_eq!(None, bounded.at(3));
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct IdentityVec {
+    len: Option<usize>,
+}
+
+impl IdentityVec {
+    /// Creates an infinite-length identity vector.
+    pub fn new() -> Self {
+        Self { len: None }
+    }
+
+    /// Creates an identity vector of length `len`, returning `None` for positions `len` and beyond.
+    pub fn bounded(len: usize) -> Self {
+        Self { len: Some(len) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn len(&self) -> Option<usize> {
+        self.len
+    }
+}
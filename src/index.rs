@@ -60,3 +60,20 @@ impl<const DIM: usize> FromIndex<DIM> for [usize; DIM] {
         index
     }
 }
+
+/// Returns the position `offset + i * stride`, or `None` if the computation overflows `usize`.
+///
+/// Adaptors mapping indices to positions by arithmetic use this helper so that pathological indices such as `usize::MAX`
+/// lead to a missing element rather than a wrapped position or a panic.
+#[inline(always)]
+pub(crate) fn checked_index(offset: usize, i: usize, stride: usize) -> Option<usize> {
+    i.checked_mul(stride).and_then(|x| x.checked_add(offset))
+}
+
+/// Converts the unsigned integer `x` into `usize`, saturating at `usize::MAX` if it does not fit, such as a large `u64` on a 32-bit target.
+///
+/// Since no storage can hold an element at position `usize::MAX`, saturated indices lead to a missing element of bounded vectors rather than a truncated position.
+#[inline(always)]
+pub(crate) fn saturating_usize<U: TryInto<usize>>(x: U) -> usize {
+    x.try_into().unwrap_or(usize::MAX)
+}
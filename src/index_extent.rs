@@ -0,0 +1,42 @@
+/// Extension of vectors which know the extent of their populated indices.
+///
+/// `index_extent` returns the per-dimension minimum and maximum of the populated indices, or `None` if the vector has no elements.
+/// This allows to construct tight iteration ranges over sparse vectors.
+///
+/// It is implemented for backends which can enumerate their elements, such as vectors, arrays and maps;
+/// it is not available for closures which cannot enumerate the indices at which they are defined.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::BTreeMap;
+///
+/// let sparse = BTreeMap::from_iter([((3, 7), 1.0), ((5, 2), 2.0)]);
+/// assert_eq!(Some(([3, 2], [5, 7])), sparse.index_extent());
+///
+/// let vec = vec![1, 2, 3];
+/// assert_eq!(Some(([0], [2])), vec.index_extent());
+///
+/// let empty: Vec<i32> = vec![];
+/// assert_eq!(None, empty.index_extent());
+/// ```
+pub trait IndexExtent<const DIM: usize> {
+    /// Returns the per-dimension minimum and maximum of the populated indices; `None` if there is no element.
+    fn index_extent(&self) -> Option<([usize; DIM], [usize; DIM])>;
+}
+
+/// Merges two extents into the extent covering both.
+pub(crate) fn merge_extents<const DIM: usize>(
+    a: Option<([usize; DIM], [usize; DIM])>,
+    b: Option<([usize; DIM], [usize; DIM])>,
+) -> Option<([usize; DIM], [usize; DIM])> {
+    match (a, b) {
+        (Some((min_a, max_a)), Some((min_b, max_b))) => Some((
+            core::array::from_fn(|d| min_a[d].min(min_b[d])),
+            core::array::from_fn(|d| max_a[d].max(max_b[d])),
+        )),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
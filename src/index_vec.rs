@@ -0,0 +1,35 @@
+/// A one-dimensional vector over any type implementing `std::ops::Index<usize>`, bounded by an explicit `len`.
+///
+/// `Index` panics on out-of-range positions and provides no way to query its bounds;
+/// therefore, the length must be provided explicitly.
+/// `at(i)` returns `Some(inner[i])` if `i < len`, and `None` otherwise without calling `inner[i]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::VecDeque;
+///
+/// let deque = VecDeque::from_iter([3, 4, 5]);
+/// let vec = IndexVec::new(deque, 3);
+///
+/// assert_eq!(Some(4), vec.at(1));
+/// assert_eq!(None, vec.at(3));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct IndexVec<V> {
+    inner: V,
+    len: usize,
+}
+
+impl<V> IndexVec<V> {
+    /// Returns a reference to the inner indexable type.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    #[inline(always)]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,75 @@
+use crate::funvec_val::FunVec;
+use ndarray::Array2;
+use std::ops::{Add, Sub};
+
+/// A summed-area table, or integral image, of a two-dimensional vector allowing rectangle-sum queries in constant time.
+///
+/// The table is stored as a `(rows + 1) x (cols + 1)` array where the first row and column are zeros,
+/// and `sat[[i + 1, j + 1]]` is the sum of all cells of the base vector within rows `0..=i` and columns `0..=j`.
+/// Missing cells of the base vector are treated as `T::default()`.
+///
+/// As a `FunVec<2, T>`, `at([i, j])` returns the prefix sum over rows `0..=i` and columns `0..=j`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// let sat = IntegralImage::from_funvec(&matrix, 2, 3);
+///
+/// assert_eq!(21, sat.rect_sum((0, 0), (2, 3)));
+/// assert_eq!(11, sat.rect_sum((1, 1), (2, 3)));
+/// assert_eq!(0, sat.rect_sum((1, 1), (1, 3)));
+///
+/// assert_eq!(Some(12), sat.at((1, 1)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntegralImage<T> {
+    sat: Array2<T>,
+}
+
+impl<T> IntegralImage<T>
+where
+    T: Clone + Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Builds the summed-area table of the `rows x cols` region of the `base` vector.
+    pub fn from_funvec<V: FunVec<2, T>>(base: &V, rows: usize, cols: usize) -> Self {
+        let mut sat = Array2::from_elem((rows + 1, cols + 1), T::default());
+        for i in 0..rows {
+            for j in 0..cols {
+                let value = base.at([i, j]).unwrap_or_default();
+                sat[[i + 1, j + 1]] = value + sat[[i, j + 1]] + sat[[i + 1, j]] - sat[[i, j]];
+            }
+        }
+        Self { sat }
+    }
+
+    /// Returns the sum of the cells within rows `r0..r1` and columns `c0..c1` in constant time.
+    ///
+    /// Ranges are half-open; they are clamped to the dimensions of the table, and empty ranges sum up to `T::default()`.
+    pub fn rect_sum(&self, (r0, c0): (usize, usize), (r1, c1): (usize, usize)) -> T {
+        let (rows, cols) = self.dimensions();
+        let (r1, c1) = (r1.min(rows), c1.min(cols));
+        let (r0, c0) = (r0.min(r1), c0.min(c1));
+        (self.sat[[r1, c1]] + self.sat[[r0, c0]]) - self.sat[[r0, c1]] - self.sat[[r1, c0]]
+    }
+}
+
+impl<T> IntegralImage<T> {
+    /// Returns the `(rows, cols)` dimensions of the base region of the table.
+    pub fn dimensions(&self) -> (usize, usize) {
+        let (rows, cols) = self.sat.dim();
+        (rows - 1, cols - 1)
+    }
+
+    /// Returns a reference to the prefix sum over rows `0..=i` and columns `0..=j`.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T> {
+        let (rows, cols) = self.dimensions();
+        match i < rows && j < cols {
+            true => self.sat.get([i + 1, j + 1]),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::funvec_ref::FunVecRef;
+
+/// Extension of two-dimensional vectors allowing to iterate over all of their elements without supplying the indices.
+///
+/// The elements are yielded together with their `(i, j)` coordinates in row-major order by walking the structure of the vector;
+/// rows might be of different lengths.
+///
+/// This is implemented for nested backends where rows can be walked, such as `Vec<Vec<T>>`, `[[T; M]; N]`,
+/// `Vec<[T; M]>`, and `HashMap<usize, Vec<T>>` or `BTreeMap<usize, Vec<T>>` where the outer keys are the row indices.
+/// On the other hand, flat-key map backends such as `HashMap<(usize, usize), T>` and closures cannot support the traversal
+/// and do not implement this trait; hence, calling `iter_all_2d` on them is a compile error.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let jagged = vec![vec![1, 2], vec![], vec![3]];
+///
+/// let all: Vec<_> = jagged.iter_all_2d().collect();
+/// assert_eq!(vec![(0, 0, &1), (0, 1, &2), (2, 0, &3)], all);
+/// ```
+pub trait IterAll2d<T>: FunVecRef<2, T> {
+    /// Returns an iterator over all elements of the vector together with their `(i, j)` coordinates in row-major order.
+    fn iter_all_2d<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a;
+}
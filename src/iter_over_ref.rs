@@ -26,7 +26,13 @@ where
 {
     type Item = Option<&'a T>;
 
+    #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         self.indices_iter.next().map(|i| self.value.ref_at(i))
     }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices_iter.size_hint()
+    }
 }
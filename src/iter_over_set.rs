@@ -0,0 +1,35 @@
+use crate::funvec_val::FunVec;
+use std::collections::BTreeSet;
+
+/// Extension of sorted map backends allowing to iterate over the elements for a sorted set of indices by intersecting the keys of the map
+/// with the set, rather than probing the map once per index.
+///
+/// Both the keys of the map and the set are walked once in sorted order; hence, the iteration takes O(n + m) time where n and m are
+/// the number of keys in the map and the number of indices in the set.
+/// This is preferable over `iter_over(set.iter().copied())`, which takes O(m log n) time, when both the map and the set are large.
+///
+/// This is implemented for `BTreeMap<Key, T>` with any key type usable as an index, such as `usize` or `(usize, usize)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::{BTreeMap, BTreeSet};
+///
+/// let ages = BTreeMap::from_iter([(2, 18), (4, 20), (7, 15), (9, 16)]);
+/// let ids = BTreeSet::from_iter([1, 4, 7, 8]);
+///
+/// let found: Vec<_> = ages.iter_over_set(&ids).collect();
+/// assert_eq!(vec![(&4, 20), (&7, 15)], found);
+/// ```
+pub trait IterOverSet<const DIM: usize, Key, T>: FunVec<DIM, T>
+where
+    T: Clone + Copy,
+{
+    /// Returns an iterator over the present elements of the vector at the indices in `set` together with their indices, in increasing order of the indices;
+    /// indices of the set which are holes of the vector are skipped.
+    fn iter_over_set<'a>(&'a self, set: &'a BTreeSet<Key>) -> impl Iterator<Item = (&'a Key, T)>
+    where
+        Key: 'a,
+        T: 'a;
+}
@@ -0,0 +1,45 @@
+use crate::funvec_val::FunVec;
+
+/// A two-dimensional vector whose columns are accessed by labels, which are translated to column indices by the `labels` function.
+///
+/// `at_labeled(i, col)` translates the label `col` into the column index `j` and returns the element of the `inner` vector at `(i, j)`;
+/// unknown labels, for which `labels` returns `None`, yield `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let scores = vec![vec![90, 75], vec![60, 85]];
+/// let columns = |label: &str| match label {
+///     "math" => Some(0),
+///     "physics" => Some(1),
+///     _ => None,
+/// };
+///
+/// let table = Labeled2::new(scores, columns);
+/// assert_eq!(Some(85), table.at_labeled(1, "physics"));
+/// assert_eq!(None, table.at_labeled(1, "history"));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Labeled2<V, L> {
+    inner: V,
+    labels: L,
+}
+
+impl<V, L> Labeled2<V, L> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the element at the `i`-th row and the column with the given label `col`;
+    /// returns `None` if the label is unknown or the element is absent.
+    pub fn at_labeled<T: Clone + Copy>(&self, i: usize, col: &str) -> Option<T>
+    where
+        V: FunVec<2, T>,
+        L: Fn(&str) -> Option<usize>,
+    {
+        (self.labels)(col).and_then(|j| self.inner.at((i, j)))
+    }
+}
@@ -0,0 +1,26 @@
+use std::collections::HashMap;
+
+/// A vector over a `HashMap` whose values are closures which lazily compute the elements on access.
+///
+/// Each `at(index)` call looks up the closure stored for the `index` and calls it; absent keys are holes.
+/// Closures are never called unless their element is accessed, which is suitable for sparse vectors
+/// with expensive-to-compute elements.
+///
+/// A wrapper is required since `HashMap<Key, T>` already implements `FunVec` returning the stored values themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let mut cells: HashMap<(usize, usize), Box<dyn Fn() -> u64>> = HashMap::new();
+/// cells.insert((0, 1), Box::new(|| (1..=10).product()));
+/// cells.insert((2, 2), Box::new(|| 42));
+///
+/// let matrix = LazyMap(cells);
+/// assert_eq!(Some(3628800), matrix.at((0, 1)));
+/// assert_eq!(Some(42), matrix.at([2, 2]));
+/// assert_eq!(None, matrix.at((1, 0)));
+/// ```
+pub struct LazyMap<Key, F>(pub HashMap<Key, F>);
@@ -0,0 +1,34 @@
+/// A two-dimensional vector whose rows are constructed lazily by the `make_row` closure on access.
+///
+/// `at([i, j])` builds the `i`-th row by `make_row(i)` and accesses its `j`-th element;
+/// rows for which `make_row` returns `None` are empty.
+///
+/// Note that the row is not cached; `make_row` is called on every access.
+/// This is suitable when rows are cheap to construct relative to their storage, or when each row is accessed rarely.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// // the i-th row is the multiplication table of i
+/// let table = LazyRows::new(|i: usize| match i {
+///     0 => None,
+///     i => Some((0..10).map(|j| i * j).collect::<Vec<_>>()),
+/// });
+///
+/// assert_eq!(Some(42), table.at([6, 7]));
+/// assert_eq!(None, table.at([0, 7]));
+/// assert_eq!(None, table.at([6, 10]));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct LazyRows<F> {
+    make_row: F,
+}
+
+impl<F> LazyRows<F> {
+    #[inline(always)]
+    pub(crate) fn make_row(&self) -> &F {
+        &self.make_row
+    }
+}
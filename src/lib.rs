@@ -11,21 +11,167 @@
     clippy::todo
 )]
 
+mod atomic_vec;
+mod auto_mat;
+mod banded;
+mod centered;
+mod chars_vec;
+mod checked_matrix;
+mod contains_index;
+mod coo2;
+mod csr;
+mod cumulative;
+mod cyclic;
 mod d1;
 mod d2;
 mod d3;
 mod d4;
 mod d_any;
+mod dense_f64_mat;
+mod dense_map;
+mod dict_cols;
+mod dyn_funvec;
 mod empty_vec;
+mod excluded;
+mod funvec_or;
 mod funvec_ref;
 mod funvec_val;
+mod identity_vec;
 mod index;
+mod index_extent;
+mod index_vec;
+mod iter_all_2d;
 mod iter_over_ref;
+mod iter_over_set;
 mod iter_over_val;
+mod labeled2;
+mod lazy_map;
+mod lazy_rows;
+mod named_vec;
+mod nan_sparse;
+mod nearest;
+mod outer_product;
+mod padded;
+mod parsed_table;
+mod permute3;
+mod pooled;
+mod quad_tree;
+mod ragged_offset;
+mod rectangular;
+mod reversed;
+mod row_cached;
+mod row_gated;
 mod scalar_as_vec;
+mod scratch_fun;
+mod slice_view;
+mod sorted_sparse;
+mod sparse_rows;
+mod stream_vec;
+mod strict;
+mod stride1;
+mod sum_axis0;
+mod toroidal2;
+mod transform;
+mod u_index;
+mod versioned;
+mod window;
 
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod array_zip;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod axis_slice;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod diag2;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod integral_image;
+#[cfg(any(feature = "impl_all", feature = "impl_lru"))]
+mod lru_memoized;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod masked2;
+#[cfg(any(feature = "impl_all", feature = "impl_mmap"))]
+mod mmap_vec;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+mod ndarray_sparse;
+#[cfg(any(feature = "impl_all", feature = "impl_rstar"))]
+mod rtree_distances;
+#[cfg(feature = "test-util")]
+mod test_util;
+
+pub use atomic_vec::AtomicVec;
+pub use auto_mat::AutoMat;
+pub use banded::Banded;
+pub use centered::Centered;
+pub use chars_vec::CharsVec;
+pub use checked_matrix::{CheckedMatrix, MatrixError};
+pub use contains_index::ContainsIndex;
+pub use coo2::Coo2;
+pub use csr::Csr;
+pub use cumulative::Cumulative;
+pub use cyclic::Cyclic;
+pub use dense_f64_mat::DenseF64Mat;
+pub use dense_map::DenseMap;
+pub use dict_cols::DictCols;
+pub use dyn_funvec::DynFunVec;
 pub use empty_vec::EmptyVec;
+pub use excluded::Excluded;
+pub use funvec_or::FunVecOr;
 pub use funvec_ref::FunVecRef;
 pub use funvec_val::FunVec;
+pub use identity_vec::IdentityVec;
 pub use index::{FromIndex, IntoIndex};
+pub use index_extent::IndexExtent;
+pub use index_vec::IndexVec;
+pub use iter_all_2d::IterAll2d;
+pub use iter_over_set::IterOverSet;
+pub use labeled2::Labeled2;
+pub use lazy_map::LazyMap;
+pub use lazy_rows::LazyRows;
+pub use named_vec::NamedVec;
+pub use nan_sparse::NanSparse;
+pub use nearest::Nearest;
+pub use outer_product::OuterProduct;
+pub use padded::Padded;
+pub use parsed_table::ParsedTable;
+pub use permute3::Permute3;
+pub use pooled::Pooled;
+pub use quad_tree::QuadTree;
+pub use ragged_offset::RaggedOffset;
+pub use rectangular::Rectangular;
+pub use reversed::Reversed;
+pub use row_cached::RowCached;
+pub use row_gated::RowGated;
 pub use scalar_as_vec::ScalarAsVec;
+pub use scratch_fun::ScratchFun;
+pub use slice_view::SliceView;
+pub use sorted_sparse::SortedSparse;
+pub use sparse_rows::SparseRows;
+pub use stream_vec::StreamVec;
+pub use strict::{Strict, StrictError};
+pub use stride1::Stride1;
+pub use sum_axis0::SumAxis0;
+pub use toroidal2::Toroidal2;
+pub use transform::Transform;
+pub use u_index::UIndex;
+pub use versioned::Versioned;
+pub use window::Window;
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+pub use array_zip::{ArrayZip2, ArrayZip3};
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+pub use axis_slice::AxisSlice;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+pub use diag2::Diag2;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+pub use integral_image::IntegralImage;
+#[cfg(any(feature = "impl_all", feature = "impl_lru"))]
+pub use lru_memoized::LruMemoized;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+pub use masked2::Masked2;
+#[cfg(any(feature = "impl_all", feature = "impl_mmap"))]
+pub use mmap_vec::MmapVec;
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+pub use ndarray_sparse::from_ndarray_sparse;
+#[cfg(any(feature = "impl_all", feature = "impl_rstar"))]
+pub use rtree_distances::{IdPoint, RTreeDistances};
+#[cfg(feature = "test-util")]
+pub use test_util::assert_funvec_close;
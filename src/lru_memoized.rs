@@ -0,0 +1,81 @@
+use lru::LruCache;
+use std::{cell::RefCell, num::NonZeroUsize};
+
+/// A vector memoizing the values of an expensive `inner` vector, such as a computed matrix, in a bounded least-recently-used cache.
+///
+/// The capacity of the cache is configured at construction by `new`; once the cache is full,
+/// computing a new value evicts the least recently used one.
+/// Only the present values are cached; holes of the inner vector are recomputed on each access.
+///
+/// The cache is kept in a `RefCell`, allowing memoization through the `&self` accessors of `FunVec`;
+/// hence, the vector is not `Sync`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::num::NonZeroUsize;
+///
+/// let expensive: Box<dyn Fn((usize, usize)) -> Option<u64>> = Box::new(|(i, j)| Some((i * j) as u64));
+///
+/// let capacity = NonZeroUsize::new(2).unwrap();
+/// let memoized = LruMemoized::new(expensive, capacity);
+///
+/// assert_eq!(Some(6), memoized.at((2, 3)));
+/// assert_eq!(Some(6), memoized.at((2, 3))); // served from the cache
+/// assert_eq!(1, memoized.len());
+/// ```
+pub struct LruMemoized<const DIM: usize, T, V> {
+    inner: V,
+    cache: RefCell<LruCache<[usize; DIM], T>>,
+}
+
+impl<const DIM: usize, T, V> LruMemoized<DIM, T, V> {
+    /// Creates a memoized vector over the `inner` vector caching at most `capacity` values.
+    pub fn new(inner: V, capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            cache: RefCell::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the maximum number of values that can be cached.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.cache.borrow().cap()
+    }
+
+    /// Returns the number of currently cached values.
+    pub fn len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Returns whether or not the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cache.borrow().is_empty()
+    }
+
+    /// Clears the cache.
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear()
+    }
+
+    /// Returns the cached value at the `index`, marking it as the most recently used; `None` if it is not cached.
+    #[inline(always)]
+    pub(crate) fn cached(&self, index: &[usize; DIM]) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.cache.borrow_mut().get(index).copied()
+    }
+
+    /// Caches the `value` at the `index`, evicting the least recently used value if the cache is full.
+    #[inline(always)]
+    pub(crate) fn cache(&self, index: [usize; DIM], value: T) {
+        self.cache.borrow_mut().put(index, value);
+    }
+}
@@ -0,0 +1,37 @@
+use ndarray::Array2;
+
+/// A two-dimensional vector over a `values` array where only the positions with a `true` in the boolean `mask` array are present.
+///
+/// `at([i, j])` returns `Some(values[[i, j]])` if and only if `mask[[i, j]]` is `true` and the position is within the bounds of both arrays;
+/// `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use ndarray::Array2;
+///
+/// let values = Array2::from_shape_vec((2, 2), vec![1, 2, 3, 4]).unwrap();
+/// let mask = Array2::from_shape_vec((2, 2), vec![true, false, false, true]).unwrap();
+///
+/// let masked = Masked2::new(&values, &mask);
+/// assert_eq!(Some(1), masked.at([0, 0]));
+/// assert_eq!(None, masked.at([0, 1]));
+/// assert_eq!(5, masked.iter_over([(0, 0), (0, 1), (1, 0), (1, 1)].into_iter()).flatten().sum());
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Masked2<'a, T> {
+    values: &'a Array2<T>,
+    mask: &'a Array2<bool>,
+}
+
+impl<'a, T> Masked2<'a, T> {
+    /// Returns the value at the position `[i, j]` if it is not masked out.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        match self.mask.get([i, j]) {
+            Some(true) => self.values.get([i, j]),
+            _ => None,
+        }
+    }
+}
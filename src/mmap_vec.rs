@@ -0,0 +1,32 @@
+use bytemuck::{Pod, PodCastError};
+
+/// A one-dimensional view over a slice of plain-old-data values, such as a memory-mapped file reinterpreted as `&[f32]`.
+///
+/// The view does not own or copy the data; each `at(i)` call reads only the `i`-th value,
+/// which allows funvec access over out-of-core data without loading all of it.
+/// Positions out of the slice bounds return `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let values = [1.5f32, 2.5, 4.0];
+/// // e.g., bytes of a memory-mapped file
+/// let bytes: &[u8] = bytemuck::cast_slice(&values);
+///
+/// let vec = MmapVec::<f32>::from_bytes(bytes).unwrap();
+/// assert_eq!(Some(2.5), vec.at(1));
+/// assert_eq!(None, vec.at(3));
+/// ```
+#[derive(Clone, Copy)]
+pub struct MmapVec<'a, T: Pod>(pub &'a [T]);
+
+impl<'a, T: Pod> MmapVec<'a, T> {
+    /// Reinterprets the `bytes` as a slice of `T` values.
+    ///
+    /// Fails if the length of `bytes` is not a multiple of the size of `T`, or if `bytes` is not aligned for `T`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, PodCastError> {
+        bytemuck::try_cast_slice(bytes).map(Self)
+    }
+}
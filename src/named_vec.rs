@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+/// A one-dimensional vector whose elements can be accessed either by their numeric indices or by their names,
+/// where the `dict` maps the names to the indices of the `data`.
+///
+/// `at(i)` returns `data[i]`, while `at_name(name)` resolves the name to its index through the dictionary;
+/// both return `None` if the index is out of range or the name is unknown.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let dict = HashMap::from_iter([("low".to_string(), 0), ("high".to_string(), 1)]);
+/// let thresholds = NamedVec::new(dict, vec![10, 90]);
+///
+/// assert_eq!(Some(&90), thresholds.at_name("high"));
+/// assert_eq!(None, thresholds.at_name("medium"));
+/// assert_eq!(Some(10), thresholds.at(0));
+/// ```
+#[derive(derive_new::new, Clone, Debug)]
+pub struct NamedVec<T> {
+    dict: HashMap<String, usize>,
+    data: Vec<T>,
+}
+
+impl<T> NamedVec<T> {
+    /// Returns a reference to the element with the given `name`; `None` if the name is unknown or maps to an out of range index.
+    pub fn at_name(&self, name: &str) -> Option<&T> {
+        self.dict.get(name).and_then(|i| self.data.get(*i))
+    }
+
+    /// Returns the index that the given `name` maps to, if known.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.dict.get(name).copied()
+    }
+
+    /// Returns a reference to the element at index `i`.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&T> {
+        self.data.get(i)
+    }
+
+    /// Returns the number of elements of the underlying data.
+    #[inline(always)]
+    pub(crate) fn len(&self) -> usize {
+        self.data.len()
+    }
+}
@@ -0,0 +1,20 @@
+/// A one-dimensional view over a dense `&[f64]` slice where holes are encoded as `NaN` values.
+///
+/// `at(i)` returns `None` if `i` is out of bounds or if the `i`-th value of the slice is `NaN`; returns `Some` of the value otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let values = [1.0, f64::NAN, 3.0];
+/// let vec = NanSparse(&values);
+///
+/// assert_eq!(Some(1.0), vec.at(0));
+/// assert_eq!(None, vec.at(1));
+/// assert_eq!(None, vec.at(3));
+///
+/// assert_eq!(4.0, vec.iter_over(0..3).flatten().sum::<f64>());
+/// ```
+#[derive(Clone, Copy)]
+pub struct NanSparse<'a>(pub &'a [f64]);
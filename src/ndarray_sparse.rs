@@ -0,0 +1,31 @@
+use ndarray::Array2;
+use std::collections::HashMap;
+
+/// Converts the dense `arr` into a sparse `HashMap<(usize, usize), f64>` keeping only the cells which are not empty.
+///
+/// A cell with value `v` is dropped if `is_empty(v)` returns true.
+/// The resulting map is a `FunVec<2, f64>` where the dropped cells are holes.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use ndarray::Array2;
+///
+/// let arr = Array2::from_shape_vec((2, 3), vec![0.0, 1.5, 0.0, 0.0, 0.0, -2.0]).unwrap();
+///
+/// let sparse = from_ndarray_sparse(&arr, |v| v.abs() < f64::EPSILON);
+///
+/// assert_eq!(2, sparse.len());
+/// assert_eq!(Some(1.5), sparse.at((0, 1)));
+/// assert_eq!(None, sparse.at((0, 0)));
+/// ```
+pub fn from_ndarray_sparse(
+    arr: &Array2<f64>,
+    is_empty: impl Fn(f64) -> bool,
+) -> HashMap<(usize, usize), f64> {
+    arr.indexed_iter()
+        .filter(|(_, v)| !is_empty(**v))
+        .map(|(ij, v)| (ij, *v))
+        .collect()
+}
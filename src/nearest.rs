@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+/// A one-dimensional vector over a `BTreeMap<usize, T>` which returns the value of the nearest populated key within a tolerance.
+///
+/// `at(i)` returns the value of the key closest to `i` among the keys in `i - tol ..= i + tol`, or `None` if there is no such key.
+/// When two keys are equally close to `i`, the lower key is preferred.
+///
+/// The ordered map allows finding the nearest keys by two range queries.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::BTreeMap;
+///
+/// let table = BTreeMap::from_iter([(10, 'a'), (20, 'b')]);
+/// let nearest = Nearest::new(&table, 3);
+///
+/// assert_eq!(Some('a'), nearest.at(12));
+/// assert_eq!(Some('b'), nearest.at(18));
+/// assert_eq!(None, nearest.at(15));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Nearest<'a, T> {
+    map: &'a BTreeMap<usize, T>,
+    tol: usize,
+}
+
+impl<'a, T> Nearest<'a, T> {
+    /// Returns the value of the nearest key to `i` within the tolerance, preferring the lower key in ties.
+    #[inline(always)]
+    pub(crate) fn nearest(&self, i: usize) -> Option<&'a T> {
+        let below = self.map.range(i.saturating_sub(self.tol)..=i).next_back();
+        let above = self.map.range(i..=i.saturating_add(self.tol)).next();
+        match (below, above) {
+            (Some((lower, x)), Some((upper, y))) => match i - lower <= upper - i {
+                true => Some(x),
+                false => Some(y),
+            },
+            (Some((_, x)), None) => Some(x),
+            (None, Some((_, y))) => Some(y),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the tolerance, the maximum distance of a key to the accessed index.
+    pub fn tol(&self) -> usize {
+        self.tol
+    }
+}
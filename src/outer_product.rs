@@ -0,0 +1,33 @@
+/// A two-dimensional vector representing the outer product of the one-dimensional vectors `a` and `b`.
+///
+/// `at((i, j))` returns `a.at(i) * b.at(j)` if both factors are present, and `None` otherwise.
+/// This compactly represents separable matrices such as costs of the form `c(i, j) = a(i) * b(j)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let product = OuterProduct::new(vec![1, 2, 3], vec![10, 100]);
+///
+/// assert_eq!(Some(200), product.at((1, 1)));
+/// assert_eq!(None, product.at((3, 0)));
+/// assert_eq!(Some([3, 2]), product.bounds());
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct OuterProduct<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> OuterProduct<A, B> {
+    #[inline(always)]
+    pub(crate) fn a(&self) -> &A {
+        &self.a
+    }
+
+    #[inline(always)]
+    pub(crate) fn b(&self) -> &B {
+        &self.b
+    }
+}
@@ -0,0 +1,50 @@
+/// A one-dimensional vector extending the `inner` vector with a constant `pad` value up to a finite length `len`.
+///
+/// * `at(i)` returns the value of the `inner` vector at `i` if present;
+/// * otherwise, returns `Some(pad)` if `i < len`;
+/// * and returns `None` for all positions beyond `len`.
+///
+/// Therefore, unlike the inner vector, `Padded` has no holes within its bounds, and its `bounds` is always `Some([len])`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let padded = Padded::new(vec![1, 2, 3], 0, 5);
+///
+/// assert_eq!(Some(2), padded.at(1));
+/// assert_eq!(Some(0), padded.at(4));
+/// assert_eq!(None, padded.at(5));
+///
+/// assert_eq!(Some([5]), padded.bounds());
+/// assert_eq!(vec![1, 2, 3, 0, 0], padded.iter_over(0..10).flatten().collect::<Vec<_>>());
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Padded<V, T> {
+    inner: V,
+    pad: T,
+    len: usize,
+}
+
+impl<V, T> Padded<V, T> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns a reference to the value used for positions within `len` which are missing in the inner vector.
+    pub fn pad(&self) -> &T {
+        &self.pad
+    }
+
+    /// Returns the length of the padded vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the length of the padded vector is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
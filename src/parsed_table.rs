@@ -0,0 +1,36 @@
+/// A two-dimensional vector over a row-major table of strings, such as a table loaded from a csv file, which parses the cells lazily on access.
+///
+/// `at((i, j))` returns `parse(&rows[i][j])`; hence, it returns `None` if the cell is out of range or cannot be parsed.
+/// This avoids a full typed conversion pass over the table.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let rows = vec![
+///     vec!["1".to_string(), "2".to_string()],
+///     vec!["x".to_string(), "4".to_string()],
+/// ];
+/// let table = ParsedTable::new(&rows, |x| x.parse::<i32>().ok());
+///
+/// assert_eq!(Some(2), table.at((0, 1)));
+/// assert_eq!(None, table.at((1, 0)));
+/// assert_eq!(None, table.at((2, 0)));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct ParsedTable<'a, T> {
+    rows: &'a [Vec<String>],
+    parse: fn(&str) -> Option<T>,
+}
+
+impl<T> ParsedTable<'_, T> {
+    /// Parses and returns the cell at the `i`-th row and `j`-th column, if present.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<T> {
+        self.rows
+            .get(i)
+            .and_then(|row| row.get(j))
+            .and_then(|cell| (self.parse)(cell))
+    }
+}
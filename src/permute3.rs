@@ -0,0 +1,52 @@
+/// A three-dimensional view over the `inner` tensor with its axes permuted by `perm`, without moving the data.
+///
+/// The `a`-th component of the inner index is the `perm[a]`-th component of the view index;
+/// i.e., `at([x0, x1, x2])` returns `inner.at([x[perm[0]], x[perm[1]], x[perm[2]]])`.
+/// For instance, `perm = [2, 1, 0]` maps the view index `(i, j, k)` to the inner index `(k, j, i)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let tensor = vec![vec![vec![1, 2, 3]]]; // shape 1 x 1 x 3
+/// let permuted = Permute3::new(tensor, [2, 1, 0]).unwrap(); // shape 3 x 1 x 1
+///
+/// assert_eq!(Some(3), permuted.at((2, 0, 0)));
+/// assert_eq!(None, permuted.at((0, 0, 2)));
+///
+/// assert!(Permute3::new(vec![vec![vec![1]]], [0, 0, 1]).is_none());
+/// ```
+#[derive(Clone)]
+pub struct Permute3<V> {
+    inner: V,
+    perm: [usize; 3],
+}
+
+impl<V> Permute3<V> {
+    /// Creates a view over the `inner` tensor with its axes permuted by `perm`; `None` if `perm` is not a permutation of `[0, 1, 2]`.
+    pub fn new(inner: V, perm: [usize; 3]) -> Option<Self> {
+        let mut sorted = perm;
+        sorted.sort_unstable();
+        match sorted == [0, 1, 2] {
+            true => Some(Self { inner, perm }),
+            false => None,
+        }
+    }
+
+    /// Returns a reference to the inner tensor.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the permutation of the axes.
+    pub fn perm(&self) -> [usize; 3] {
+        self.perm
+    }
+
+    /// Returns the inner index corresponding to the `index` of the view.
+    #[inline(always)]
+    pub(crate) fn permute(&self, index: [usize; 3]) -> [usize; 3] {
+        self.perm.map(|a| index[a])
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+/// A one-dimensional vector over the slots of an object pool, where the `freed` slots are logically deleted.
+///
+/// `at(i)` returns `None` if the `i`-th slot is freed or out of range, and `data[i]` otherwise;
+/// hence, pooled storage can be presented as a sparse funvec.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashSet;
+///
+/// let data = vec![10, 20, 30];
+/// let freed = HashSet::from_iter([1]);
+/// let pool = Pooled::new(&data, &freed);
+///
+/// assert_eq!(Some(10), pool.at(0));
+/// assert_eq!(None, pool.at(1));
+/// assert_eq!(None, pool.at(3));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Pooled<'a, T> {
+    data: &'a [T],
+    freed: &'a HashSet<usize>,
+}
+
+impl<'a, T> Pooled<'a, T> {
+    /// Returns the element at the `i`-th slot unless it is freed.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&'a T> {
+        match self.freed.contains(&i) {
+            true => None,
+            false => self.data.get(i),
+        }
+    }
+
+    /// Returns the number of slots of the pool, including the freed ones.
+    #[inline(always)]
+    pub(crate) fn num_slots(&self) -> usize {
+        self.data.len()
+    }
+}
@@ -0,0 +1,161 @@
+/// Maximum number of points stored in a leaf before it is split into four quadrants.
+const LEAF_CAPACITY: usize = 8;
+
+/// A two-dimensional sparse vector backed by a region quadtree, which is well suited for spatially clustered sparse data.
+///
+/// The tree covers the square region `[0, 2 * half) x [0, 2 * half)` where `half` is a power of two, growing as larger positions are inserted.
+/// Each branch splits its region into four quadrants by the `half` bit of the row and column;
+/// each leaf stores up to a small number of `((i, j), value)` points and is split once it exceeds its capacity.
+/// Hence, `at((i, j))` descends O(log max(i, j)) levels in the worst case and far fewer for clustered data;
+/// it returns `None` if the position is absent.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let mut tree = QuadTree::from_entries([((1, 2), 12), ((100, 200), 3)]);
+/// tree.insert((2, 1), 21);
+///
+/// assert_eq!(3, tree.len());
+/// assert_eq!(Some(12), tree.at((1, 2)));
+/// assert_eq!(Some(&21), tree.ref_at((2, 1)));
+/// assert_eq!(None, tree.at((2, 2)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuadTree<T> {
+    root: Node<T>,
+    half: usize,
+    len: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Node<T> {
+    Leaf(Vec<((usize, usize), T)>),
+    Branch(Box<[Node<T>; 4]>),
+}
+
+impl<T> Default for QuadTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> QuadTree<T> {
+    /// Creates an empty quadtree.
+    pub fn new() -> Self {
+        Self {
+            root: Node::Leaf(vec![]),
+            half: 1,
+            len: 0,
+        }
+    }
+
+    /// Creates a quadtree from the given `entries`; when there exist multiple entries with the same position, the last one wins.
+    pub fn from_entries<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = ((usize, usize), T)>,
+    {
+        let mut tree = Self::new();
+        for (ij, value) in entries {
+            tree.insert(ij, value);
+        }
+        tree
+    }
+
+    /// Inserts the `value` at the `(i, j)` position, returning the value previously at this position if any.
+    pub fn insert(&mut self, (i, j): (usize, usize), value: T) -> Option<T> {
+        while !self.covers(i, j) {
+            self.grow();
+        }
+        let previous = self.root.insert(i, j, value, self.half);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Returns the number of points stored in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the value at the `(i, j)` position, if present.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T> {
+        match self.covers(i, j) {
+            true => self.root.get(i, j, self.half),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    fn covers(&self, i: usize, j: usize) -> bool {
+        ((i | j) >> 1) < self.half
+    }
+
+    fn grow(&mut self) {
+        if let Node::Branch(_) = self.root {
+            let root = std::mem::replace(&mut self.root, Node::Leaf(vec![]));
+            self.root = Node::Branch(Box::new([
+                root,
+                Node::Leaf(vec![]),
+                Node::Leaf(vec![]),
+                Node::Leaf(vec![]),
+            ]));
+        }
+        self.half *= 2;
+    }
+}
+
+impl<T> Node<T> {
+    fn get(&self, i: usize, j: usize, half: usize) -> Option<&T> {
+        match self {
+            Self::Leaf(points) => points
+                .iter()
+                .find(|(ij, _)| *ij == (i, j))
+                .map(|(_, value)| value),
+            Self::Branch(children) => children[quadrant(i, j, half)].get(i, j, half / 2),
+        }
+    }
+
+    fn insert(&mut self, i: usize, j: usize, value: T, half: usize) -> Option<T> {
+        match self {
+            Self::Leaf(points) => {
+                if let Some((_, existing)) = points.iter_mut().find(|(ij, _)| *ij == (i, j)) {
+                    return Some(std::mem::replace(existing, value));
+                }
+                points.push(((i, j), value));
+                if points.len() > LEAF_CAPACITY {
+                    self.split(half);
+                }
+                None
+            }
+            Self::Branch(children) => children[quadrant(i, j, half)].insert(i, j, value, half / 2),
+        }
+    }
+
+    fn split(&mut self, half: usize) {
+        let branch = Self::Branch(Box::new([
+            Self::Leaf(vec![]),
+            Self::Leaf(vec![]),
+            Self::Leaf(vec![]),
+            Self::Leaf(vec![]),
+        ]));
+        if let Self::Leaf(points) = std::mem::replace(self, branch) {
+            for ((i, j), value) in points {
+                self.insert(i, j, value, half);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn quadrant(i: usize, j: usize, half: usize) -> usize {
+    (((i & half) != 0) as usize) << 1 | ((j & half) != 0) as usize
+}
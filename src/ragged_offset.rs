@@ -0,0 +1,31 @@
+/// A two-dimensional ragged vector where each row stores its values starting at a different column, i.e., with a leading gap.
+///
+/// Each row is represented by a `(start, values)` pair, where `values[k]` is the element at column `start + k`.
+/// Hence, `at((i, j))` returns `values[j - start]` of the `i`-th row if `start <= j < start + values.len()`, and `None` otherwise.
+/// This models banded-per-row storage.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let rows = vec![(0, vec![1, 2]), (3, vec![4, 5])];
+/// let ragged = RaggedOffset(&rows);
+///
+/// assert_eq!(Some(2), ragged.at((0, 1)));
+/// assert_eq!(None, ragged.at((1, 2)));
+/// assert_eq!(Some(4), ragged.at((1, 3)));
+/// assert_eq!(None, ragged.at((1, 5)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct RaggedOffset<'a, T>(pub &'a [(usize, Vec<T>)]);
+
+impl<'a, T> RaggedOffset<'a, T> {
+    /// Returns the element at the `i`-th row and `j`-th column, if present.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        self.0
+            .get(i)
+            .and_then(|(start, values)| j.checked_sub(*start).and_then(|k| values.get(k)))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::funvec_val::FunVec;
+
+/// Extension of two-dimensional vectors allowing to cheaply check whether all of their rows have the same length.
+///
+/// This is implemented for nested backends where rows can be walked, such as `Vec<Vec<T>>`, `[V1; N]`,
+/// and `HashMap<usize, V1>` or `BTreeMap<usize, V1>` where the outer keys are the row indices.
+/// Each row's length is read from its `bounds`; hence, a row with unknown bounds, such as a `HashMap` or a closure row, is never considered rectangular.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let rectangular = vec![vec![1, 2], vec![3, 4]];
+/// assert!(rectangular.is_rectangular(2));
+/// assert!(!rectangular.is_rectangular(3));
+///
+/// let jagged = vec![vec![1, 2], vec![3]];
+/// assert!(!jagged.is_rectangular(2));
+/// ```
+pub trait Rectangular<T>: FunVec<2, T>
+where
+    T: Clone + Copy,
+{
+    /// Returns whether or not every row of the vector has exactly `expected_cols` columns; `false` if any row differs or has unknown bounds.
+    fn is_rectangular(&self, expected_cols: usize) -> bool;
+}
@@ -0,0 +1,29 @@
+/// A one-dimensional reversed view over a slice, without moving or copying the data.
+///
+/// `at(i)` returns the element at position `len - 1 - i` of the slice, or `None` if `i` is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let data = [1, 2, 3];
+/// let reversed = Reversed(&data);
+///
+/// assert_eq!(Some(3), reversed.at(0));
+/// assert_eq!(Some(1), reversed.at(2));
+/// assert_eq!(None, reversed.at(3));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Reversed<'a, T>(pub &'a [T]);
+
+impl<'a, T> Reversed<'a, T> {
+    /// Returns the element at position `i` of the reversed view.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&'a T> {
+        self.0
+            .len()
+            .checked_sub(i.saturating_add(1))
+            .map(|position| &self.0[position])
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+/// A one-dimensional view over a single row of a `HashMap<usize, V1>` which caches the reference to the current row.
+///
+/// Accessing a `HashMap<usize, V1>` as a `FunVec<2, _>` hashes the outer key on every `at` call.
+/// In row-major sweeps, the outer key stays the same for many consecutive calls.
+/// `RowCached` looks up the row only once when it is set; subsequent `at(j)` calls directly access the cached row.
+///
+/// Rows which do not exist in the map behave as empty rows, returning `None` for all columns.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let matrix = HashMap::from_iter([(0, vec![1, 2, 3]), (2, vec![7, 8])]);
+///
+/// let mut row = RowCached::new(&matrix, 0);
+/// assert_eq!(6, row.iter_over(0..3).flatten().sum());
+///
+/// row.advance();
+/// assert_eq!(1, row.row_index());
+/// assert_eq!(None, row.at(0));
+///
+/// row.advance();
+/// assert_eq!(Some(8), row.at(1));
+/// assert_eq!(None, row.at(2));
+/// ```
+pub struct RowCached<'a, V1> {
+    rows: &'a HashMap<usize, V1>,
+    row_index: usize,
+    row: Option<&'a V1>,
+}
+
+impl<'a, V1> RowCached<'a, V1> {
+    /// Creates a view over the `row_index`-th row of the `rows`.
+    pub fn new(rows: &'a HashMap<usize, V1>, row_index: usize) -> Self {
+        Self {
+            rows,
+            row_index,
+            row: rows.get(&row_index),
+        }
+    }
+
+    /// Index of the row currently cached.
+    pub fn row_index(&self) -> usize {
+        self.row_index
+    }
+
+    /// Moves the view to the `row_index`-th row; this is the only place where the outer lookup takes place.
+    pub fn set_row(&mut self, row_index: usize) {
+        self.row_index = row_index;
+        self.row = self.rows.get(&row_index);
+    }
+
+    /// Moves the view to the next row; the row index saturates at `usize::MAX`.
+    pub fn advance(&mut self) {
+        self.set_row(self.row_index.saturating_add(1))
+    }
+
+    #[inline(always)]
+    pub(crate) fn row(&self) -> Option<&'a V1> {
+        self.row
+    }
+}
@@ -0,0 +1,37 @@
+/// A two-dimensional vector which gates entire rows of the `inner` vector by the `active` predicate.
+///
+/// `at((i, j))` returns `None` if `active(i)` is false, and delegates to the inner vector otherwise.
+/// Gating whole rows is coarser but cheaper than masking individual cells.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let matrix = vec![vec![1, 2], vec![3, 4]];
+/// let gated = RowGated::new(matrix, |i: usize| i != 1);
+///
+/// assert_eq!(Some(2), gated.at((0, 1)));
+/// assert_eq!(None, gated.at((1, 0)));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct RowGated<V, P> {
+    inner: V,
+    active: P,
+}
+
+impl<V, P> RowGated<V, P> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns whether or not the `i`-th row is active.
+    #[inline(always)]
+    pub(crate) fn is_active(&self, i: usize) -> bool
+    where
+        P: Fn(usize) -> bool,
+    {
+        (self.active)(i)
+    }
+}
@@ -0,0 +1,58 @@
+use rstar::{primitives::GeomWithData, RTree};
+use std::collections::HashMap;
+
+/// Two-dimensional points identified by their ids as stored in an `rstar::RTree`.
+pub type IdPoint = GeomWithData<[f64; 2], usize>;
+
+/// A two-dimensional cost matrix of Euclidean distances between the points of an `rstar::RTree`, where each point is identified by its id.
+///
+/// `at((i, j))` returns the distance between the points with ids `i` and `j`, or `None` if either of the ids is not in the tree.
+/// Points are indexed by their ids once at construction; besides, the tree allows spatial queries such as `nearest`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use rstar::RTree;
+///
+/// let tree = RTree::bulk_load(vec![
+///     IdPoint::new([0.0, 0.0], 0),
+///     IdPoint::new([3.0, 4.0], 1),
+///     IdPoint::new([1.0, 0.0], 2),
+/// ]);
+/// let distances = RTreeDistances::new(&tree);
+///
+/// assert_eq!(Some(5.0), distances.at((0, 1)));
+/// assert_eq!(None, distances.at((0, 3)));
+/// assert_eq!(Some(2), distances.nearest(0));
+/// ```
+#[derive(Clone)]
+pub struct RTreeDistances<'a> {
+    tree: &'a RTree<IdPoint>,
+    locations: HashMap<usize, [f64; 2]>,
+}
+
+impl<'a> RTreeDistances<'a> {
+    /// Creates the distance matrix over the points of the `tree`, indexing them by their ids.
+    pub fn new(tree: &'a RTree<IdPoint>) -> Self {
+        let locations = tree.iter().map(|p| (p.data, *p.geom())).collect();
+        Self { tree, locations }
+    }
+
+    /// Returns the id of the point nearest to the point with id `i`, excluding itself; `None` if `i` is not in the tree or it is the only point.
+    pub fn nearest(&self, i: usize) -> Option<usize> {
+        let location = self.locations.get(&i)?;
+        self.tree
+            .nearest_neighbor_iter(*location)
+            .map(|p| p.data)
+            .find(|j| *j != i)
+    }
+
+    /// Returns the distance between the points with ids `i` and `j`, if both are in the tree.
+    #[inline(always)]
+    pub(crate) fn distance(&self, i: usize, j: usize) -> Option<f64> {
+        let [x1, y1] = self.locations.get(&i)?;
+        let [x2, y2] = self.locations.get(&j)?;
+        Some((x1 - x2).hypot(y1 - y2))
+    }
+}
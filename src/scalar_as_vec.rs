@@ -64,4 +64,18 @@
 /// assert_eq!(Some(42), numbers.at([14, 1, 0]));
 /// assert_eq!(Some(42), numbers.at((4, 1, 3, 6))); // array or tuple indices can be used interchangeably
 /// ```
+///
+/// Collections of `ScalarAsVec` rows, such as `Vec<ScalarAsVec<T>>` or `HashMap<usize, ScalarAsVec<T>>`, represent the row-uniform pattern:
+/// a matrix where each row is a constant.
+/// Both `at((i, j))` and `ref_at((i, j))` return the scalar of the `i`-th row for any column `j`, without allocating the rows.
+///
+/// ```rust
+/// # use orx_funvec::*;
+///
+/// let row_uniform = vec![ScalarAsVec(1), ScalarAsVec(2)];
+///
+/// assert_eq!(Some(2), row_uniform.at((1, 0)));
+/// assert_eq!(Some(&2), row_uniform.ref_at((1, 1000)));
+/// assert_eq!(None, row_uniform.at((2, 0)));
+/// ```
 pub struct ScalarAsVec<T>(pub T);
@@ -0,0 +1,68 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+};
+
+thread_local! {
+    static SCRATCHES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// A one-dimensional vector over a compute-heavy function `f` which receives a mutable scratch `S` in addition to the index,
+/// such that buffers can be reused across calls without per-call allocation.
+///
+/// `at(i)` returns `f(i, &mut scratch)`, where the scratch is kept in a thread-local storage and is created by `S::default()` on first use.
+/// Each thread has its own scratch per scratch type `S`; hence, the scratch is never shared across threads and requires no synchronization.
+/// The scratch is shared by all `ScratchFun`s using the same scratch type on the same thread, and `f` must not rely on its previous contents.
+/// When a `ScratchFun` is accessed within `f` of another one with the same scratch type, the inner call receives a fresh default scratch.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// // sum of the divisors of i, collected into a reused buffer
+/// let divisor_sum = ScratchFun::new(|i: usize, divisors: &mut Vec<usize>| {
+///     divisors.clear();
+///     divisors.extend((1..=i).filter(|d| i % d == 0));
+///     Some(divisors.iter().sum::<usize>())
+/// });
+///
+/// assert_eq!(Some(12), divisor_sum.at(6));
+/// assert_eq!(Some(1), divisor_sum.at(1));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct ScratchFun<F, S> {
+    f: F,
+    #[new(default)]
+    phantom: std::marker::PhantomData<fn(S)>,
+}
+
+impl<F, S: Default + 'static> ScratchFun<F, S> {
+    /// Calls `f` with the `i`-th index and the thread-local scratch.
+    #[inline(always)]
+    pub(crate) fn call<T>(&self, i: usize) -> Option<T>
+    where
+        F: Fn(usize, &mut S) -> Option<T>,
+    {
+        let mut scratch = take_scratch::<S>();
+        let value = (self.f)(i, &mut scratch);
+        put_scratch(scratch);
+        value
+    }
+}
+
+fn take_scratch<S: Default + 'static>() -> Box<S> {
+    SCRATCHES
+        .with(|scratches| scratches.borrow_mut().remove(&TypeId::of::<S>()))
+        .and_then(|scratch| scratch.downcast().ok())
+        .unwrap_or_default()
+}
+
+fn put_scratch<S: 'static>(scratch: Box<S>) {
+    SCRATCHES.with(|scratches| {
+        scratches
+            .borrow_mut()
+            .insert(TypeId::of::<S>(), scratch as Box<dyn Any>)
+    });
+}
@@ -0,0 +1,52 @@
+use std::ops::Range;
+
+/// A one-dimensional bounded and rebased window over the `inner` vector, created by `FunVec::slice_vec`.
+///
+/// `at(i)` returns `inner.at(range.start + i)` if `i < range.len()`, and `None` otherwise;
+/// hence, index `0` of the view corresponds to `range.start` of the inner vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let vec = vec![0, 10, 20, 30, 40];
+/// let view = vec.slice_vec(1..4);
+///
+/// assert_eq!(Some(10), view.at(0));
+/// assert_eq!(Some(30), view.at(2));
+/// assert_eq!(None, view.at(3));
+/// assert_eq!(Some([3]), view.bounds());
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct SliceView<'a, V> {
+    inner: &'a V,
+    range: Range<usize>,
+}
+
+impl<V> SliceView<'_, V> {
+    /// Returns the number of positions of the view.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns whether or not the view is empty.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns the position in the inner vector corresponding to the `i`-th position of the view; `None` if out of the view.
+    #[inline(always)]
+    pub(crate) fn position(&self, i: usize) -> Option<usize> {
+        match i < self.len() {
+            true => Some(self.range.start + i),
+            false => None,
+        }
+    }
+
+    /// Returns a reference to the inner vector.
+    #[inline(always)]
+    pub(crate) fn inner(&self) -> &V {
+        self.inner
+    }
+}
@@ -0,0 +1,42 @@
+/// A one-dimensional sparse vector over a slice of `(index, value)` pairs sorted by their indices.
+///
+/// `at(i)` binary searches the pairs for index `i`; hence, each access costs O(log n) where n is the number of stored pairs.
+/// The pairs are expected to be sorted by their indices without duplicates; otherwise, results of the search are unspecified.
+///
+/// Rows of sorted sparse vectors, such as `Vec<SortedSparse<T>>`, form a CSR-like row-sorted sparse matrix implementing `FunVec<2, T>`:
+/// * the outer vector stores one row per position, which is accessed in constant time;
+/// * each row stores only its present `(column, value)` pairs contiguously, sorted by column,
+///   which are searched in O(log n) time where n is the number of present cells of the row.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let pairs = [(1, 10), (4, 40), (7, 70)];
+/// let sparse = SortedSparse(&pairs);
+///
+/// assert_eq!(Some(40), sparse.at(4));
+/// assert_eq!(None, sparse.at(5));
+///
+/// let row0 = [(0, 1), (3, 2)];
+/// let row1 = [(2, 3)];
+/// let matrix = vec![SortedSparse(&row0), SortedSparse(&row1)];
+///
+/// assert_eq!(Some(2), matrix.at((0, 3)));
+/// assert_eq!(Some(3), matrix.at((1, 2)));
+/// assert_eq!(None, matrix.at((1, 3)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct SortedSparse<'a, T>(pub &'a [(usize, T)]);
+
+impl<'a, T> SortedSparse<'a, T> {
+    /// Returns the value at index `i`, if present.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&'a T> {
+        self.0
+            .binary_search_by_key(&i, |(index, _)| *index)
+            .ok()
+            .map(|position| &self.0[position].1)
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+/// A two-dimensional vector where all rows are equal to a shared `default` row, except for the rows in `overrides`.
+///
+/// `at((i, j))` returns `overrides[i].at(j)` if the `i`-th row is overridden, and `default.at(j)` otherwise.
+/// Storing only the differing rows together with a single default row is memory-optimal for near-uniform matrices.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let overrides = HashMap::from_iter([(2, vec![7, 8, 9])]);
+/// let matrix = SparseRows::new(overrides, vec![1, 2, 3]);
+///
+/// assert_eq!(Some(2), matrix.at((0, 1)));
+/// assert_eq!(Some(8), matrix.at((2, 1)));
+/// assert_eq!(Some(3), matrix.at((1000, 2)));
+/// assert_eq!(None, matrix.at((2, 3)));
+/// ```
+#[derive(derive_new::new, Clone, Debug)]
+pub struct SparseRows<V1> {
+    overrides: HashMap<usize, V1>,
+    default: V1,
+}
+
+impl<V1> SparseRows<V1> {
+    /// Returns the `i`-th row, which is either its override or the default row.
+    #[inline(always)]
+    pub(crate) fn row(&self, i: usize) -> &V1 {
+        self.overrides.get(&i).unwrap_or(&self.default)
+    }
+
+    /// Returns a mutable reference to the overridden rows.
+    pub fn overrides_mut(&mut self) -> &mut HashMap<usize, V1> {
+        &mut self.overrides
+    }
+}
@@ -0,0 +1,60 @@
+use std::{cell::RefCell, iter::Fuse};
+
+/// A one-dimensional vector over a single-pass iterator, which pulls elements from the iterator on demand and caches them.
+///
+/// `at(i)` advances the iterator up to the `i`-th element if it is not consumed yet, caching all elements pulled on the way;
+/// it returns `None` if the iterator ends before the `i`-th element.
+///
+/// Hence, random access to already consumed indices works through the cache, while far-ahead access buffers all intermediate elements.
+/// Note that accessing a far-ahead index of an infinite iterator consumes and caches as many elements.
+///
+/// The iterator and the cache are kept in `RefCell`s, allowing to advance through the `&self` accessors of `FunVec`;
+/// hence, the vector is not `Sync`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let stream = StreamVec::new((0..).map(|x| x * 10));
+///
+/// assert_eq!(Some(30), stream.at(3));
+/// assert_eq!(4, stream.num_cached());
+///
+/// assert_eq!(Some(10), stream.at(1)); // from the cache
+/// assert_eq!(4, stream.num_cached());
+/// ```
+pub struct StreamVec<I: Iterator> {
+    iter: RefCell<Fuse<I>>,
+    cache: RefCell<Vec<I::Item>>,
+}
+
+impl<I: Iterator> StreamVec<I> {
+    /// Creates a vector over the elements of the single-pass `iter`.
+    pub fn new<J: IntoIterator<IntoIter = I>>(iter: J) -> Self {
+        Self {
+            iter: RefCell::new(iter.into_iter().fuse()),
+            cache: RefCell::new(vec![]),
+        }
+    }
+
+    /// Returns the number of elements pulled from the iterator so far.
+    pub fn num_cached(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Returns the `i`-th element of the stream, pulling elements from the iterator if required.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<I::Item>
+    where
+        I::Item: Copy,
+    {
+        let mut cache = self.cache.borrow_mut();
+        if cache.len() <= i {
+            let mut iter = self.iter.borrow_mut();
+            let num_missing = (i - cache.len()).saturating_add(1);
+            cache.extend(iter.by_ref().take(num_missing));
+        }
+        cache.get(i).copied()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{funvec_val::FunVec, index::IntoIndex};
+use std::fmt::Display;
+
+/// Error of a strict access to a `Strict` matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrictError {
+    /// The row of the index does not exist in the matrix.
+    MissingRow {
+        /// Row of the accessed index.
+        row: usize,
+        /// Number of rows of the matrix.
+        num_rows: usize,
+    },
+    /// The row of the index exists; however, the column exceeds the length of the row.
+    OutOfColumn {
+        /// Row of the accessed index.
+        row: usize,
+        /// Column of the accessed index.
+        col: usize,
+        /// Length of the row, i.e., its bounds.
+        row_len: usize,
+    },
+}
+
+impl Display for StrictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingRow { row, num_rows } => {
+                write!(
+                    f,
+                    "row {} is missing in the matrix with {} rows",
+                    row, num_rows
+                )
+            }
+            Self::OutOfColumn { row, col, row_len } => write!(
+                f,
+                "column {} is out of row {} with length {}",
+                col, row, row_len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StrictError {}
+
+/// A two-dimensional jagged matrix of rows which, in addition to the lenient `at`, provides the strict `try_at` distinguishing
+/// out-of-bounds accesses from holes, which helps catching index computation bugs.
+///
+/// `try_at((i, j))` returns
+/// * `Err(StrictError::MissingRow)` if the `i`-th row does not exist,
+/// * `Err(StrictError::OutOfColumn)` if the `i`-th row exists and its bounds is known, but `j` is not within the bounds,
+/// * `Ok(self.at((i, j)))` otherwise, which might be `Ok(None)` for holes of rows with unknown bounds such as a `HashMap`.
+///
+/// `at((i, j))` is lenient and returns `None` in all of the erroneous cases.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let matrix = Strict::new(vec![vec![1, 2, 3], vec![4]]);
+///
+/// assert_eq!(Ok(Some(4)), matrix.try_at((1, 0)));
+/// assert_eq!(
+///     Err(StrictError::OutOfColumn { row: 1, col: 2, row_len: 1 }),
+///     matrix.try_at((1, 2))
+/// );
+/// assert_eq!(
+///     Err(StrictError::MissingRow { row: 2, num_rows: 2 }),
+///     matrix.try_at((2, 0))
+/// );
+///
+/// assert_eq!(None, matrix.at((1, 2)));
+/// assert_eq!(None, matrix.at((2, 0)));
+/// ```
+#[derive(derive_new::new, Clone, Debug)]
+pub struct Strict<V1> {
+    rows: Vec<V1>,
+}
+
+impl<V1> Strict<V1> {
+    /// Returns the element at the given `index` of the matrix, or the error describing why the index is out of bounds.
+    pub fn try_at<T, Idx>(&self, index: Idx) -> Result<Option<T>, StrictError>
+    where
+        T: Clone + Copy,
+        V1: FunVec<1, T>,
+        Idx: IntoIndex<2>,
+    {
+        let [i, j] = index.into_index();
+        let row = self.rows.get(i).ok_or(StrictError::MissingRow {
+            row: i,
+            num_rows: self.rows.len(),
+        })?;
+        match row.bounds() {
+            Some([row_len]) if j >= row_len => Err(StrictError::OutOfColumn {
+                row: i,
+                col: j,
+                row_len,
+            }),
+            _ => Ok(row.at(j)),
+        }
+    }
+
+    /// Returns the rows of the matrix.
+    pub fn rows(&self) -> &[V1] {
+        &self.rows
+    }
+}
@@ -0,0 +1,49 @@
+use crate::index::checked_index;
+
+/// A one-dimensional strided view over a slice, such as one channel of interleaved data.
+///
+/// `at(i)` returns the element at position `offset + i * stride` of the `data`, or `None` if the position is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// // interleaved (left, right) audio samples
+/// let samples = [1, -1, 2, -2, 3, -3];
+///
+/// let left = Stride1::new(&samples, 2, 0);
+/// let right = Stride1::new(&samples, 2, 1);
+///
+/// assert_eq!(Some(3), left.at(2));
+/// assert_eq!(Some(-2), right.at(1));
+/// assert_eq!(None, right.at(3));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Stride1<'a, T> {
+    data: &'a [T],
+    stride: usize,
+    offset: usize,
+}
+
+impl<'a, T> Stride1<'a, T> {
+    /// Returns the element at the `i`-th position of the strided view.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&'a T> {
+        checked_index(self.offset, i, self.stride).and_then(|position| self.data.get(position))
+    }
+
+    /// Returns the number of elements of the strided view; `None` if the `stride` is zero, in which case the view is unbounded.
+    pub fn len(&self) -> Option<usize> {
+        match (self.stride, self.data.len().checked_sub(self.offset)) {
+            (0, Some(1..)) => None,
+            (_, None | Some(0)) => Some(0),
+            (stride, Some(remaining)) => Some((remaining - 1) / stride + 1),
+        }
+    }
+
+    /// Returns whether or not the strided view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == Some(0)
+    }
+}
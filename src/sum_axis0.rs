@@ -0,0 +1,36 @@
+/// A lazy one-dimensional reduction of a two-dimensional `inner` vector, where the `j`-th element is the sum of the `j`-th column
+/// over the first `rows` rows; created by `FunVec::sum_axis0`.
+///
+/// The row count is configured explicitly since sparse backends do not have one.
+/// Holes are skipped; `at(j)` is `None` only if all cells of the `j`-th column within the rows are holes.
+/// Each access sums the column anew, costing `rows` accesses to the inner vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let matrix = vec![vec![1, 2, 3], vec![4, 5]];
+/// let column_sums = matrix.sum_axis0(2);
+///
+/// assert_eq!(Some(5), column_sums.at(0));
+/// assert_eq!(Some(3), column_sums.at(2));
+/// assert_eq!(None, column_sums.at(3));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct SumAxis0<V> {
+    inner: V,
+    rows: usize,
+}
+
+impl<V> SumAxis0<V> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the number of rows summed over.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+}
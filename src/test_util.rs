@@ -0,0 +1,40 @@
+use crate::funvec_val::FunVec;
+
+/// Asserts that the elements of the `vec` at the `expected` positions are present and within `eps` of the expected values.
+///
+/// Exact equality of floating point numbers is fragile; this helper compares each `vec.at(i)` with the expected value `x`
+/// by `(vec.at(i) - x).abs() < eps`.
+///
+/// # Panics
+///
+/// Panics if any of the expected positions is a hole of the `vec`, or if any of the elements is not within `eps` of the expected value.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let vec = vec![0.1 + 0.2, 1.0 / 3.0];
+/// assert_funvec_close(&vec, &[(0, 0.3), (1, 0.3333)], 1e-3);
+/// ```
+pub fn assert_funvec_close<V: FunVec<1, f64>>(vec: &V, expected: &[(usize, f64)], eps: f64) {
+    for (i, x) in expected {
+        let value = vec.at(*i);
+        assert!(
+            value.is_some(),
+            "element at {} is missing, expected {}",
+            i,
+            x
+        );
+        if let Some(value) = value {
+            assert!(
+                (value - x).abs() < eps,
+                "element at {} is {}, which is not within {} of {}",
+                i,
+                value,
+                eps,
+                x
+            );
+        }
+    }
+}
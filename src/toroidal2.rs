@@ -0,0 +1,39 @@
+/// A two-dimensional vector wrapping around both dimensions of the first `rows x cols` cells of the `inner` vector, i.e., a torus.
+///
+/// `at((i, j))` returns `inner.at((i % rows, j % cols))`; hence, the vector is unbounded in both dimensions and wrap-around stencils
+/// such as those of cellular automata can be evaluated without special handling at the edges.
+/// The values are `None` only at the holes of the inner vector within the first `rows x cols` cells, or at all positions if either `rows` or `cols` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let grid = Toroidal2::new(vec![vec![1, 2, 3], vec![4, 5, 6]], 2, 3);
+///
+/// assert_eq!(Some(1), grid.at((2, 3)));
+/// assert_eq!(Some(6), grid.at((3, 5)));
+///
+/// // left neighbor of (0, 0) wraps to the last column
+/// assert_eq!(Some(3), grid.at((0, 3 - 1)));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Toroidal2<V> {
+    inner: V,
+    rows: usize,
+    cols: usize,
+}
+
+impl<V> Toroidal2<V> {
+    /// Returns a reference to the inner vector.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the position in the inner vector that the given `(i, j)` position wraps to; `None` if either of the dimensions is zero.
+    #[inline(always)]
+    pub(crate) fn wrap(&self, i: usize, j: usize) -> Option<[usize; 2]> {
+        i.checked_rem(self.rows)
+            .and_then(|i| j.checked_rem(self.cols).map(|j| [i, j]))
+    }
+}
@@ -0,0 +1,48 @@
+use std::marker::PhantomData;
+
+/// A vector which remaps each index by `remap`, accesses the `inner` vector at the remapped index and maps the found value by `map`.
+///
+/// Remapping the index and mapping the value are fused into a single adaptor layer;
+/// hence, it is equivalent to first remapping the indices and then mapping the values by two separate adaptors,
+/// while avoiding the nested adaptor types.
+///
+/// Holes of the inner vector remain holes; i.e., `map` is called only for the present values.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+///
+/// // transpose and scale
+/// let transformed = matrix.transform(|[i, j]| [j, i], |x| x * 10);
+///
+/// assert_eq!(Some(40), transformed.at([0, 1]));
+/// assert_eq!(Some(30), transformed.at([2, 0]));
+/// assert_eq!(None, transformed.at([0, 2]));
+/// ```
+#[derive(derive_new::new, Clone)]
+pub struct Transform<V, T, G, H> {
+    inner: V,
+    remap: G,
+    map: H,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<V, T, G, H> Transform<V, T, G, H> {
+    #[inline(always)]
+    pub(crate) fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    #[inline(always)]
+    pub(crate) fn remap(&self) -> &G {
+        &self.remap
+    }
+
+    #[inline(always)]
+    pub(crate) fn map(&self) -> &H {
+        &self.map
+    }
+}
@@ -0,0 +1,45 @@
+use crate::index::{saturating_usize, IntoIndex};
+
+/// A wrapper allowing indices of `u32` or `u64` primitives, such as those received from an external API, to be used directly as funvec indices.
+///
+/// * `UIndex<u32>` and `UIndex<u64>` implement `IntoIndex<1>`;
+/// * `UIndex<(u32, u32)>` and `UIndex<(u64, u64)>` implement `IntoIndex<2>`;
+/// * heterogeneous-width `UIndex<(u32, usize)>` and `UIndex<(usize, u32)>` implement `IntoIndex<2>`, such as a `u32` node id together with a `usize` layer;
+/// * `UIndex<[u32; DIM]>` and `UIndex<[u64; DIM]>` implement `IntoIndex<DIM>`.
+///
+/// `IntoIndex` is not implemented for the bare primitives or tuples since it would make integer literal indices such as `vec.at(3)` or `matrix.at((1, 2))` ambiguous.
+///
+/// Values which do not fit in `usize`, such as a large `u64` on a 32-bit target, saturate to `usize::MAX` rather than being truncated;
+/// hence, bounded vectors gracefully return `None` for them.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+///
+/// let vec = vec![10, 20, 30];
+/// let matrix = vec![vec![1, 2], vec![3, 4]];
+///
+/// let i: u32 = 2;
+/// assert_eq!(Some(30), vec.at(UIndex(i)));
+/// assert_eq!(None, vec.at(UIndex(u64::MAX)));
+/// assert_eq!(Some(3), matrix.at(UIndex((1u32, 0u32))));
+/// assert_eq!(Some(&2), matrix.ref_at(UIndex([0u64, 1])));
+/// assert_eq!(Some(4), matrix.at(UIndex((1u32, 1usize))));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UIndex<I>(pub I);
+
+impl<const DIM: usize> IntoIndex<DIM> for UIndex<[u32; DIM]> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        self.0.map(saturating_usize)
+    }
+}
+
+impl<const DIM: usize> IntoIndex<DIM> for UIndex<[u64; DIM]> {
+    #[inline(always)]
+    fn into_index(self) -> [usize; DIM] {
+        self.0.map(saturating_usize)
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+/// A one-dimensional snapshot of versioned overrides as of a fixed `version`.
+///
+/// The `map` stores the history of each index as a list of `(version, value)` pairs.
+/// `at(i)` returns the value with the latest version which is less than or equal to `version` among the history of index `i`;
+/// it is `None` if the index has no history or all of its values are of later versions.
+/// The histories are not required to be sorted by version; if the same version appears more than once, the last one wins.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::HashMap;
+///
+/// let history = HashMap::from_iter([(0, vec![(1, 'a'), (5, 'b')]), (1, vec![(3, 'x')])]);
+///
+/// let snapshot = Versioned::new(&history, 4);
+/// assert_eq!(Some('a'), snapshot.at(0));
+/// assert_eq!(Some('x'), snapshot.at(1));
+///
+/// let snapshot = snapshot.with_version(2);
+/// assert_eq!(Some('a'), snapshot.at(0));
+/// assert_eq!(None, snapshot.at(1));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Versioned<'a, T> {
+    map: &'a HashMap<usize, Vec<(u64, T)>>,
+    version: u64,
+}
+
+impl<'a, T> Versioned<'a, T> {
+    /// Returns the value of index `i` as of the version of the snapshot.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&'a T> {
+        self.map.get(&i).and_then(|history| {
+            history
+                .iter()
+                .filter(|(version, _)| *version <= self.version)
+                .max_by_key(|(version, _)| *version)
+                .map(|(_, value)| value)
+        })
+    }
+
+    /// Returns the version of the snapshot.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns the snapshot of the same overrides as of the given `version`.
+    pub fn with_version(self, version: u64) -> Self {
+        Self { version, ..self }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::index::checked_index;
+use std::collections::VecDeque;
+
+/// A one-dimensional window over a `VecDeque` starting at the logical `head` position of the deque.
+///
+/// `at(i)` returns the element at position `head + i` of the deque, i.e., `deque.get(head + i)`;
+/// hence, index `0` is the start of the window rather than the front of the deque.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_funvec::*;
+/// use std::collections::VecDeque;
+///
+/// let series = VecDeque::from_iter([1, 2, 3, 4, 5]);
+/// let window = Window::new(&series, 2);
+///
+/// assert_eq!(Some(3), window.at(0));
+/// assert_eq!(Some(5), window.at(2));
+/// assert_eq!(None, window.at(3));
+/// ```
+#[derive(derive_new::new, Clone, Copy)]
+pub struct Window<'a, T> {
+    deque: &'a VecDeque<T>,
+    head: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    /// Returns the element at position `i` of the window.
+    #[inline(always)]
+    pub(crate) fn get(&self, i: usize) -> Option<&'a T> {
+        checked_index(self.head, i, 1).and_then(|position| self.deque.get(position))
+    }
+
+    /// Returns the position of the deque where the window starts.
+    pub fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Moves the start of the window by `by` positions.
+    pub fn advance(&mut self, by: usize) {
+        self.head = self.head.saturating_add(by);
+    }
+}
@@ -87,6 +87,17 @@ fn smallvec() {
     ref_assert_contagious(&vec);
 }
 
+#[cfg(any(feature = "impl_all", feature = "impl_generic_array"))]
+#[test]
+fn generic_array() {
+    use generic_array::GenericArray;
+
+    let arr: GenericArray<i32, generic_array::typenum::U3> = GenericArray::from_array([1, 2, 3]);
+    val_assert_contagious(&arr);
+    ref_assert_contagious(&arr);
+    assert_eq!(None, arr.at(100));
+}
+
 // maps
 fn val_assert_maps<V: FunVec<1, i32>>(vec: &V) {
     // 1->10 ; 2->20 ; 7->70
@@ -233,3 +244,1106 @@ fn box_dyn_fn() {
     });
     val_assert_maps(&closure);
 }
+
+// borrowed dyn Fn
+#[test]
+fn ref_dyn_fn() {
+    let valid_indices: HashSet<usize> = HashSet::from_iter([1, 2, 7]);
+    let closure = move |i: usize| match valid_indices.contains(&i) {
+        true => Some((i * 10) as i32),
+        false => None,
+    };
+    let closure: &dyn Fn(usize) -> Option<i32> = &closure;
+    val_assert_maps(&closure);
+}
+
+// booleans
+fn assert_bools<V: FunVec<1, bool> + FunVecRef<1, bool>>(vec: &V) {
+    // [true, false, true]
+
+    assert_eq!(Some(true), vec.at(0));
+    assert_eq!(Some(false), vec.at(1));
+    assert_eq!(Some(true), vec.at(2));
+    assert_eq!(None, vec.at(3));
+
+    assert_eq!(Some(&false), vec.ref_at(1));
+    assert_eq!(None, vec.ref_at(3));
+
+    assert_eq!(3, vec.iter_over(0..10).flatten().count());
+    assert_eq!(2, vec.iter_over(0..10).flatten().filter(|x| *x).count());
+    assert_eq!(7, vec.iter_over(0..10).filter(|x| x.is_none()).count());
+}
+
+#[test]
+fn vec_of_bools_stores_booleans() {
+    let vec = vec![true, false, true];
+    assert_bools(&vec);
+}
+
+#[test]
+fn array_of_bools_stores_booleans() {
+    let arr = [true, false, true];
+    assert_bools(&arr);
+}
+
+// sum_over_with
+#[test]
+fn sum_over_with_index_dependent_holes() {
+    use std::collections::HashMap;
+
+    let vec = vec![100, 200, 300];
+    assert_eq!(600 + 3 + 4, vec.sum_over_with(0..5, |[i]| i as i32));
+    assert_eq!(
+        300 + 7,
+        vec.sum_over_with([2, 7].into_iter(), |[i]| i as i32)
+    );
+
+    let map = HashMap::from_iter([(1, 100), (3, 300)]);
+    assert_eq!(100 + 300 + 2 + 4, map.sum_over_with(0..5, |[i]| i as i32));
+
+    let empty: EmptyVec<i32> = EmptyVec::new();
+    assert_eq!(10, empty.sum_over_with(0..5, |[i]| i as i32));
+
+    let scalar = ScalarAsVec(7);
+    assert_eq!(35, scalar.sum_over_with(0..5, |[i]| i as i32));
+}
+
+// atomics
+#[test]
+fn atomic_vec_reflects_current_values() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let counters: Vec<_> = (0..4).map(AtomicUsize::new).collect();
+    let vec = AtomicVec(&counters);
+
+    assert_eq!(Some(2), vec.at(2));
+    assert_eq!(None, vec.at(4));
+    assert_eq!(6, vec.iter_over(0..10).flatten().sum::<usize>());
+
+    counters[2].store(20, Ordering::Relaxed);
+    counters[3].fetch_add(10, Ordering::Relaxed);
+
+    assert_eq!(Some(20), vec.at(2));
+    assert_eq!(Some(13), vec.at(3));
+    assert_eq!(34, vec.iter_over(0..10).flatten().sum::<usize>());
+
+    std::thread::scope(|s| {
+        s.spawn(|| counters[0].fetch_add(5, Ordering::Relaxed));
+    });
+    assert_eq!(Some(5), vec.at(0));
+}
+
+// padded
+#[test]
+fn padded_vec() {
+    let padded = Padded::new(vec![1, 2, 3], 42, 6);
+
+    assert_eq!(Some(3), padded.at(2));
+    assert_eq!(Some(42), padded.at(3));
+    assert_eq!(Some(&42), padded.ref_at(5));
+    assert_eq!(None, padded.at(6));
+    assert_eq!(None, padded.ref_at(100));
+
+    assert_eq!(Some([6]), padded.bounds());
+    assert_eq!(6 + 3 * 42, padded.iter_over(0..100).flatten().sum());
+}
+
+#[test]
+fn padded_shorter_than_inner() {
+    let padded = Padded::new(vec![1, 2, 3], 42, 2);
+
+    assert_eq!(Some(2), padded.at(1));
+    assert_eq!(None, padded.at(2));
+    assert_eq!(3, padded.iter_over(0..100).flatten().sum());
+}
+
+#[test]
+fn padded_sparse_inner() {
+    use std::collections::HashMap;
+
+    let padded = Padded::new(HashMap::from_iter([(1, 10), (7, 70)]), -1, 4);
+    assert_eq!(
+        vec![-1, 10, -1, -1],
+        padded.iter_over(0..8).flatten().collect::<Vec<_>>()
+    );
+}
+
+// bounds
+#[test]
+fn bounds() {
+    use std::collections::HashMap;
+
+    assert_eq!(Some([3]), vec![1, 2, 3].bounds());
+    assert_eq!(Some([2]), [1, 2].bounds());
+    assert_eq!(Some([0]), FunVec::<1, i32>::bounds(&EmptyVec::new()));
+    assert_eq!(None, FunVec::<1, i32>::bounds(&ScalarAsVec(3)));
+    assert_eq!(None, HashMap::<usize, i32>::new().bounds());
+}
+
+// diagonal
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_diagonal() {
+    use ndarray::Array2;
+
+    let matrix = Array2::from_shape_fn((3, 4), |(i, j)| (10 * i + j) as i32);
+    let diagonal = Diag2(&matrix);
+
+    assert_eq!(Some(11), diagonal.at(1));
+    assert_eq!(Some(&22), diagonal.ref_at(2));
+    assert_eq!(None, diagonal.at(3));
+    assert_eq!(Some([3]), diagonal.bounds());
+
+    assert_eq!(33, diagonal.iter_over(0..10).flatten().sum());
+}
+
+// nan sparse
+#[test]
+fn nan_sparse() {
+    let values = vec![1.0, f64::NAN, 2.0, f64::NAN, 4.0];
+    let vec = NanSparse(&values);
+
+    assert_eq!(Some(1.0), vec.at(0));
+    assert_eq!(None, vec.at(1));
+    assert_eq!(Some(&2.0), vec.ref_at(2));
+    assert_eq!(None, vec.ref_at(3));
+    assert_eq!(None, vec.at(5));
+
+    assert_eq!(3, vec.iter_over(0..10).flatten().count());
+    assert_eq!(7.0, vec.iter_over(0..10).flatten().sum::<f64>());
+    assert!(vec.iter_over(0..10).flatten().all(|x| !x.is_nan()));
+}
+
+// ndarray huge indices
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_huge_indices() {
+    use ndarray::Array1;
+
+    let arr = Array1::from_vec(vec![1, 2, 3]);
+    assert_eq!(None, arr.at(usize::MAX));
+    assert_eq!(None, arr.ref_at(usize::MAX));
+    assert_eq!(None, arr.view().at(usize::MAX));
+    assert_eq!(None, arr.view().ref_at(usize::MAX));
+}
+
+// identity
+#[test]
+fn identity_vec() {
+    let identity = IdentityVec::new();
+    assert_eq!(
+        vec![0, 1, 2, 3, 4],
+        identity.iter_over(0..5).flatten().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(usize::MAX), identity.at(usize::MAX));
+    assert_eq!(None, identity.bounds());
+
+    let bounded = IdentityVec::bounded(3);
+    assert_eq!(
+        vec![Some(0), Some(1), Some(2), None, None],
+        bounded.iter_over(0..5).collect::<Vec<_>>()
+    );
+    assert_eq!(Some([3]), bounded.bounds());
+}
+
+// or
+#[test]
+fn funvec_or_tiers() {
+    use std::collections::HashMap;
+
+    let tier1 = HashMap::from_iter([(0, 1)]);
+    let tier2 = HashMap::from_iter([(0, 2), (1, 2)]);
+    let tier3 = HashMap::from_iter([(0, 3), (1, 3), (2, 3)]);
+
+    let vec = funvec_or!(tier1, tier2, tier3, ScalarAsVec(4));
+
+    assert_eq!(Some(1), vec.at(0));
+    assert_eq!(Some(2), vec.at(1));
+    assert_eq!(Some(3), vec.at(2));
+    assert_eq!(Some(4), vec.at(3));
+    assert_eq!(Some(&4), vec.ref_at(1000));
+    assert_eq!(None, FunVec::<1, i32>::bounds(&vec));
+}
+
+#[test]
+fn funvec_or_method() {
+    use std::collections::HashMap;
+
+    let vec = HashMap::from_iter([(1, 10)])
+        .or(HashMap::from_iter([(2, 20)]))
+        .or(vec![0, 1, 2, 3]);
+
+    assert_eq!(
+        vec![0, 10, 20, 3],
+        vec.iter_over(0..10).flatten().collect::<Vec<_>>()
+    );
+    assert_eq!(None, vec.at(4));
+
+    let bounded = vec![1, 2].or([0, 0, 0]);
+    assert_eq!(Some([3]), bounded.bounds());
+}
+
+// cyclic
+#[test]
+fn cyclic_vec() {
+    let cyclic = Cyclic::new(vec![1, 2, 3], 3);
+
+    assert_eq!(Some(2), cyclic.at(7));
+    assert_eq!(Some(&2), cyclic.ref_at(7));
+    assert_eq!(Some(1), cyclic.at(3000));
+    assert_eq!(10 * 6, cyclic.iter_over(0..30).flatten().sum());
+    assert_eq!(None, cyclic.bounds());
+
+    let shorter_cycle = Cyclic::new(vec![1, 2, 3], 2);
+    assert_eq!(
+        vec![1, 2, 1, 2],
+        shorter_cycle.iter_over(0..4).flatten().collect::<Vec<_>>()
+    );
+
+    let longer_cycle = Cyclic::new(vec![1, 2, 3], 4);
+    assert_eq!(None, longer_cycle.at(7));
+
+    let empty_cycle = Cyclic::new(vec![1, 2, 3], 0);
+    assert_eq!(None, empty_cycle.at(0));
+    assert_eq!(None, empty_cycle.ref_at(5));
+}
+
+// mmap
+#[cfg(any(feature = "impl_all", feature = "impl_mmap"))]
+#[test]
+fn mmap_vec_over_bytes() {
+    let values = [1.0f32, -2.5, 3.25, 0.0];
+    let bytes: &[u8] = bytemuck::cast_slice(&values);
+
+    let vec = MmapVec::<f32>::from_bytes(bytes).unwrap();
+    assert_eq!(Some(1.0), vec.at(0));
+    assert_eq!(Some(-2.5), vec.at(1));
+    assert_eq!(Some(&3.25), vec.ref_at(2));
+    assert_eq!(None, vec.at(4));
+    assert_eq!(None, vec.ref_at(42));
+    assert_eq!(Some([4]), vec.bounds());
+    assert_eq!(1.75, vec.iter_over(0..10).flatten().sum::<f32>());
+
+    assert!(MmapVec::<f32>::from_bytes(&bytes[..5]).is_err());
+}
+
+// centered
+#[test]
+fn centered_vec() {
+    let centered = Centered::new(vec![10, 20, 30, 40, 50], 2);
+
+    let values: Vec<_> = (-2..=2).map(|i| centered.at_signed(i)).collect();
+    assert_eq!(
+        vec![Some(10), Some(20), Some(30), Some(40), Some(50)],
+        values
+    );
+
+    assert_eq!(None, centered.at_signed(-3));
+    assert_eq!(None, centered.at_signed(3));
+    assert_eq!(None, centered.at_signed(isize::MIN));
+    assert_eq!(None, centered.at_signed(isize::MAX));
+
+    let stencil_sum: i32 = (-1..=1).filter_map(|i| centered.at_signed(i)).sum();
+    assert_eq!(90, stencil_sum);
+}
+
+// size hint
+#[test]
+fn iter_over_size_hint() {
+    let vec = vec![1, 2, 3];
+    assert_eq!((5, Some(5)), vec.iter_over(0..5).size_hint());
+    assert_eq!(
+        (2, Some(2)),
+        vec.ref_iter_over([0, 7].into_iter()).size_hint()
+    );
+}
+
+// index vec
+struct Squares;
+impl std::ops::Index<usize> for Squares {
+    type Output = usize;
+    fn index(&self, index: usize) -> &Self::Output {
+        const SQUARES: [usize; 4] = [0, 1, 4, 9];
+        &SQUARES[index]
+    }
+}
+
+#[test]
+fn index_vec_bounded_access() {
+    let vec = IndexVec::new(Squares, 4);
+
+    assert_eq!(Some(9), vec.at(3));
+    assert_eq!(Some(&4), vec.ref_at(2));
+    assert_eq!(None, vec.at(4));
+    assert_eq!(None, vec.ref_at(100));
+    assert_eq!(Some([4]), vec.bounds());
+    assert_eq!(14, vec.iter_over(0..10).flatten().sum::<usize>());
+
+    let shorter = IndexVec::new(Squares, 2);
+    assert_eq!(None, shorter.at(2));
+}
+
+// nearest
+#[test]
+fn nearest_within_tolerance() {
+    let table =
+        std::collections::BTreeMap::from_iter([(0, 0.0), (10, 1.0), (14, 1.4), (100, 10.0)]);
+
+    let nearest = Nearest::new(&table, 2);
+    assert_eq!(Some(0.0), nearest.at(0));
+    assert_eq!(Some(0.0), nearest.at(2));
+    assert_eq!(None, nearest.at(3));
+    assert_eq!(Some(1.0), nearest.at(8));
+    assert_eq!(Some(1.0), nearest.at(12)); // tie between 10 and 14 prefers the lower key
+    assert_eq!(Some(1.4), nearest.at(13));
+    assert_eq!(Some(&1.4), nearest.ref_at(16));
+    assert_eq!(None, nearest.at(17));
+    assert_eq!(None, nearest.at(97));
+    assert_eq!(Some(10.0), nearest.at(102));
+    assert_eq!(None, nearest.at(usize::MAX));
+
+    let exact = Nearest::new(&table, 0);
+    assert_eq!(Some(1.0), exact.at(10));
+    assert_eq!(None, exact.at(11));
+
+    let unbounded = Nearest::new(&table, usize::MAX);
+    assert_eq!(Some(10.0), unbounded.at(usize::MAX));
+}
+
+// stride
+#[test]
+fn stride1_even_and_odd() {
+    let data = [0, 10, 20, 30, 40, 50, 60, 70];
+
+    let even = Stride1::new(&data, 2, 0);
+    assert_eq!(
+        vec![0, 20, 40, 60],
+        even.iter_over(0..10).flatten().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(4), even.len());
+
+    let odd = Stride1::new(&data, 2, 1);
+    assert_eq!(
+        vec![10, 30, 50, 70],
+        odd.ref_iter_over(0..10)
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(None, odd.at(4));
+    assert_eq!(None, odd.at(usize::MAX));
+    assert_eq!(Some([4]), odd.bounds());
+
+    assert_eq!(Some(3), Stride1::new(&data, 3, 0).len());
+    assert_eq!(Some(2), Stride1::new(&data, 3, 2).len());
+    assert!(Stride1::new(&data, 2, 8).is_empty());
+    assert_eq!(None, Stride1::new(&data, 2, 8).at(0));
+
+    let constant = Stride1::new(&data, 0, 3);
+    assert_eq!(Some(30), constant.at(1000));
+    assert_eq!(None, constant.len());
+}
+
+// test-util
+#[cfg(feature = "test-util")]
+#[test]
+fn assert_funvec_close_within_eps() {
+    let vec = vec![1.0, 2.0 + 1e-9, 3.0 - 1e-7, -4.0];
+    assert_funvec_close(&vec, &[(0, 1.0), (1, 2.0), (2, 3.0), (3, -4.0)], 1e-6);
+    assert_funvec_close(&vec, &[(2, 3.0), (0, 1.0)], 1e-6);
+    assert_funvec_close(&vec, &[], 1e-6);
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+#[should_panic(expected = "not within")]
+fn assert_funvec_close_out_of_eps() {
+    let vec = vec![1.0, 2.0 + 1e-3];
+    assert_funvec_close(&vec, &[(0, 1.0), (1, 2.0)], 1e-6);
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+#[should_panic(expected = "missing")]
+fn assert_funvec_close_missing() {
+    let vec = vec![1.0];
+    assert_funvec_close(&vec, &[(1, 1.0)], 1e-6);
+}
+
+// result
+#[test]
+fn result_ok_delegates_err_is_empty() {
+    let ok: Result<Vec<i32>, String> = Ok(vec![1, 2, 3]);
+    assert_eq!(Some(2), ok.at(1));
+    assert_eq!(Some(&3), ok.ref_at(2));
+    assert_eq!(None, ok.at(3));
+    assert_eq!(Some([3]), ok.bounds());
+    val_assert_contagious(&ok);
+    ref_assert_contagious(&ok);
+
+    let err: Result<Vec<i32>, String> = Err(String::from("failed to build"));
+    assert_eq!(None, err.at(0));
+    assert_eq!(None, err.ref_at(1));
+    assert_eq!(Some([0]), err.bounds());
+    assert_eq!(0, err.iter_over(0..10).flatten().count());
+
+    let rows: Vec<Result<Vec<i32>, ()>> = vec![Ok(vec![1, 2]), Err(()), Ok(vec![3])];
+    assert_eq!(Some(2), rows.at((0, 1)));
+    assert_eq!(None, rows.at((1, 0)));
+    assert_eq!(Some(&3), rows.ref_at([2, 0]));
+}
+
+// nalgebra
+#[cfg(any(feature = "impl_all", feature = "impl_nalgebra"))]
+#[test]
+fn nalgebra_coordinates() {
+    use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+    let point = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(Some(1.0), point.at(0));
+    assert_eq!(Some(2.0), point.at(1));
+    assert_eq!(Some(&3.0), point.ref_at(2));
+    assert_eq!(None, point.at(3));
+    assert_eq!(Some([3]), point.bounds());
+
+    let point = Point2::new(4, 5);
+    assert_eq!(
+        vec![4, 5],
+        point.iter_over(0..5).flatten().collect::<Vec<_>>()
+    );
+
+    let vector = Vector3::new(-1, -2, -3);
+    assert_eq!(Some(-3), vector.at(2));
+    assert_eq!(None, vector.ref_at(3));
+
+    let vector = Vector2::new(0.5, 1.5);
+    assert_eq!(Some(&1.5), vector.ref_at(1));
+    assert_eq!(None, vector.at(2));
+    assert_eq!(Some([2]), vector.bounds());
+}
+
+// cumulative
+#[test]
+fn cumulative_prefix_sums() {
+    let values = vec![3, -1, 4, 1, 5];
+    let cumulative = Cumulative::new(values.clone());
+
+    assert_eq!(Some(values[..4].iter().sum()), cumulative.at(3));
+    for i in 0..values.len() {
+        assert_eq!(Some(values[..=i].iter().sum()), cumulative.at(i));
+    }
+    assert_eq!(None, cumulative.at(5));
+    assert_eq!(Some([5]), cumulative.bounds());
+
+    let sparse = std::collections::HashMap::from_iter([(1, 10), (3, 30)]);
+    let cumulative = Cumulative::new(sparse);
+    assert_eq!(
+        vec![0, 10, 10, 40, 40],
+        cumulative.iter_over(0..5).flatten().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(40), cumulative.at(100));
+}
+
+// dense map
+#[test]
+fn dense_map_defaults_within_len() {
+    let map = std::collections::HashMap::from_iter([(0, 5), (2, 7), (9, 99)]);
+    let dense = DenseMap::new(&map, 5, -1);
+
+    assert_eq!(Some(5), dense.at(0));
+    assert_eq!(Some(-1), dense.at(1));
+    assert_eq!(Some(&7), dense.ref_at(2));
+    assert_eq!(Some(&-1), dense.ref_at(4));
+    assert_eq!(None, dense.at(5));
+    assert_eq!(None, dense.at(9)); // stored but beyond len
+    assert_eq!(Some([5]), dense.bounds());
+    assert_eq!(
+        5 - 1 + 7 - 1 - 1,
+        dense.iter_over(0..100).flatten().sum::<i32>()
+    );
+
+    let empty = DenseMap::new(&map, 0, 0);
+    assert!(empty.is_empty());
+    assert_eq!(None, empty.at(0));
+}
+
+// window
+#[test]
+fn window_over_ring_buffer() {
+    use std::collections::VecDeque;
+
+    let mut ring = VecDeque::with_capacity(4);
+    for x in 0..6 {
+        if ring.len() == 4 {
+            ring.pop_front();
+        }
+        ring.push_back(x);
+    }
+    // ring: [2, 3, 4, 5]
+
+    let mut window = Window::new(&ring, 1);
+    assert_eq!(Some(3), window.at(0));
+    assert_eq!(Some(&5), window.ref_at(2));
+    assert_eq!(None, window.at(3));
+    assert_eq!(None, window.at(usize::MAX));
+    assert_eq!(12, window.iter_over(0..10).flatten().sum::<i32>());
+
+    window.advance(2);
+    assert_eq!(3, window.head());
+    assert_eq!(Some(5), window.at(0));
+    assert_eq!(None, window.at(1));
+
+    window.advance(usize::MAX);
+    assert_eq!(None, window.at(0));
+}
+
+// into_boxed_fn
+#[test]
+fn into_boxed_fn_erases_type() {
+    use std::collections::HashMap;
+    type Erased = Box<dyn Fn([usize; 1]) -> Option<i32>>;
+
+    let erased: Vec<Erased> = vec![
+        vec![1, 2, 3].into_boxed_fn(),
+        HashMap::from_iter([(1, 10), (2, 20), (7, 70)]).into_boxed_fn(),
+    ];
+
+    val_assert_contagious(&erased[0]);
+    val_assert_maps(&erased[1]);
+
+    let sums: Vec<i32> = erased
+        .iter()
+        .map(|vec| vec.iter_over(0..10).flatten().sum())
+        .collect();
+    assert_eq!(vec![6, 100], sums);
+}
+
+// wrapping
+#[test]
+fn wrapping_sums_wrap_around() {
+    use std::num::Wrapping;
+
+    let vec = vec![
+        Wrapping(i32::MAX),
+        Wrapping(1),
+        Wrapping(i32::MAX),
+        Wrapping(5),
+    ];
+
+    let total: Wrapping<i32> = vec.iter_over(0..10).flatten().sum();
+    assert_eq!(
+        Wrapping(
+            i32::MAX
+                .wrapping_add(1)
+                .wrapping_add(i32::MAX)
+                .wrapping_add(5)
+        ),
+        total
+    );
+    assert_eq!(Wrapping(4), total);
+
+    let ref_total: Wrapping<i32> = vec.ref_iter_over(0..2).flatten().sum();
+    assert_eq!(Wrapping(i32::MIN), ref_total);
+
+    let with_holes = vec.sum_over_with([0, 1, 9].into_iter(), |_| Wrapping(i32::MAX));
+    assert_eq!(Wrapping(i32::MIN) + Wrapping(i32::MAX), with_holes);
+
+    let bytes = [Wrapping(200u8), Wrapping(100)];
+    assert_eq!(Wrapping(44u8), bytes.iter_over(0..2).flatten().sum());
+}
+
+// reversed
+#[test]
+fn reversed_view() {
+    let data = vec![1, 2, 3, 4, 5];
+    let reversed = Reversed(&data);
+
+    assert_eq!(Some(5), reversed.at(0));
+    assert_eq!(Some(&1), reversed.ref_at(4));
+    assert_eq!(None, reversed.at(5));
+    assert_eq!(None, reversed.at(usize::MAX));
+    assert_eq!(Some([5]), reversed.bounds());
+
+    let values: Vec<_> = reversed.iter_over(0..10).flatten().collect();
+    let expected: Vec<_> = data.iter().rev().copied().collect();
+    assert_eq!(expected, values);
+
+    let empty: Reversed<i32> = Reversed(&[]);
+    assert_eq!(None, empty.at(0));
+}
+
+// rayon
+#[cfg(any(feature = "impl_all", feature = "impl_rayon"))]
+#[test]
+fn par_reduce_over_equals_sequential() {
+    let vec: Vec<i64> = (0..100_000).map(|x| (x * 7919) % 1000 - 500).collect();
+
+    let sequential: i64 = vec.iter_over(0..200_000).flatten().sum();
+    let parallel = vec.par_reduce_over(0..200_000usize, 0, |x, y| x + y);
+    assert_eq!(sequential, parallel);
+    assert_eq!(sequential, vec.sum_over_with(0..100_000, |_| 0));
+
+    let max = vec.par_reduce_over(0..100_000usize, i64::MIN, i64::max);
+    assert_eq!(vec.iter().max().copied(), Some(max));
+
+    let map = std::collections::HashMap::from_iter([(3, 1), (100, 2), (5000, 3)]);
+    let indices: Vec<usize> = (0..10_000).collect();
+    assert_eq!(6, map.par_reduce_over(indices, 0, |x, y| x + y));
+}
+
+// text
+#[test]
+fn chars_vec_multi_byte() {
+    let text = "héllo, 世界!";
+    let chars = CharsVec(text);
+
+    assert_eq!(Some('h'), chars.at(0));
+    assert_eq!(Some('é'), chars.at(1));
+    assert_eq!(Some('l'), chars.at(2));
+    assert_eq!(Some('世'), chars.at(7));
+    assert_eq!(Some('界'), chars.at(8));
+    assert_eq!(Some('!'), chars.at(9));
+    assert_eq!(None, chars.at(10));
+    assert_eq!(None, chars.at(text.len()));
+    assert_eq!(Some([10]), chars.bounds());
+    assert_eq!(text, chars.iter_over(0..100).flatten().collect::<String>());
+
+    assert_eq!(None, CharsVec("").at(0));
+}
+
+// slices
+#[test]
+fn slices() {
+    let bytes = "héllo".as_bytes();
+    assert_eq!(Some(b'h'), bytes.at(0));
+    assert_eq!(Some(0xc3), bytes.at(1));
+    assert_eq!(Some(&b'o'), bytes.ref_at(5));
+    assert_eq!(None, bytes.at(6));
+    assert_eq!(Some([6]), bytes.bounds());
+
+    let vec = vec![1, 2, 3];
+    val_assert_contagious(&vec.as_slice());
+    ref_assert_contagious(&&vec[..]);
+
+    let rows: Vec<&[i32]> = vec![&vec[..2], &vec[1..]];
+    assert_eq!(Some(3), rows.at((1, 1)));
+    assert_eq!(None, rows.at((0, 2)));
+}
+
+// diff_over
+#[test]
+fn diff_over_series() {
+    let series = vec![3, 5, 4, 10, 10];
+    assert_eq!(
+        vec![Some(2), Some(-1), Some(6), Some(0)],
+        series.diff_over(0..4)
+    );
+    assert_eq!(vec![Some(6), Some(0), None], series.diff_over(2..5));
+    assert!(series.diff_over(3..3).is_empty());
+
+    let sparse = std::collections::HashMap::from_iter([(0, 1.0), (1, 1.5), (3, 3.0), (4, 2.0)]);
+    assert_eq!(
+        vec![Some(0.5), None, None, Some(-1.0)],
+        sparse.diff_over(0..4)
+    );
+
+    let identity = IdentityVec::new();
+    assert_eq!(
+        vec![Some(1); 3],
+        identity.diff_over(usize::MAX - 4..usize::MAX - 1)
+    );
+}
+
+// overflowing indices
+#[test]
+fn index_arithmetic_does_not_overflow() {
+    let data = [1, 2, 3, 4];
+
+    let strided = Stride1::new(&data, 3, 1);
+    assert_eq!(None, strided.at(usize::MAX));
+    assert_eq!(None, strided.at(usize::MAX / 3 + 1));
+    assert_eq!(None, Stride1::new(&data, usize::MAX, usize::MAX).at(1));
+
+    let cyclic = Cyclic::new(data, 4);
+    assert_eq!(Some(4), cyclic.at(usize::MAX));
+    assert_eq!(None, Cyclic::new(data, usize::MAX).at(usize::MAX - 1));
+
+    let deque = std::collections::VecDeque::from_iter(data);
+    assert_eq!(None, Window::new(&deque, usize::MAX).at(1));
+    assert_eq!(None, Window::new(&deque, 2).at(usize::MAX));
+}
+
+// pooled
+#[test]
+fn pooled_freed_slots() {
+    let data: Vec<i32> = (0..6).map(|i| 10 * i).collect();
+    let mut freed = HashSet::new();
+    freed.insert(1);
+    freed.insert(4);
+    freed.insert(42);
+
+    let pool = Pooled::new(&data, &freed);
+    for (i, value) in data.iter().enumerate() {
+        match freed.contains(&i) {
+            true => {
+                assert_eq!(None, pool.at(i));
+                assert_eq!(None, pool.ref_at(i));
+            }
+            false => {
+                assert_eq!(Some(*value), pool.at(i));
+                assert_eq!(Some(value), pool.ref_at(i));
+            }
+        }
+    }
+
+    assert_eq!(None, pool.at(6));
+    assert_eq!(None, pool.at(42));
+    assert_eq!(Some([6]), pool.bounds());
+    assert_eq!(20 + 30 + 50, pool.iter_over(0..6).flatten().sum::<i32>());
+}
+
+// normalize_over
+#[test]
+fn normalize_sparse_weights() {
+    let weights = std::collections::BTreeMap::from_iter([(0, 2.0), (3, 0.5), (7, 1.5), (9, 4.0)]);
+
+    let normalized = weights.normalize_over(0..8);
+    let indices: Vec<_> = normalized.iter().map(|(i, _)| *i).collect();
+    assert_eq!(vec![0, 3, 7], indices);
+
+    let total: f64 = normalized.iter().map(|(_, x)| x).sum();
+    assert!((total - 1.0).abs() < 1e-12);
+    assert!((normalized[0].1 - 0.5).abs() < 1e-12);
+    assert!((normalized[1].1 - 0.125).abs() < 1e-12);
+
+    let all = weights.normalize_over(0..10);
+    let total: f64 = all.iter().map(|(_, x)| x).sum();
+    assert!((total - 1.0).abs() < 1e-12);
+
+    assert!(weights.normalize_over(4..7).is_empty());
+    assert!(vec![0.0, 0.0].normalize_over(0..2).is_empty());
+}
+
+// named
+#[test]
+fn named_vec_resolves_names() {
+    use std::collections::HashMap;
+
+    let names = ["alpha", "beta", "gamma"];
+    let dict: HashMap<_, _> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.to_string(), i))
+        .chain([("dangling".to_string(), 7)])
+        .collect();
+    let named = NamedVec::new(
+        dict,
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+    );
+
+    for (i, name) in names.iter().enumerate() {
+        assert_eq!(Some(i), named.index_of(name));
+        assert_eq!(named.ref_at(i), named.at_name(name));
+    }
+    assert_eq!(Some(&"b".to_string()), named.at_name("beta"));
+
+    assert_eq!(None, named.at_name("delta"));
+    assert_eq!(None, named.at_name("dangling"));
+    assert_eq!(None, named.ref_at(3));
+
+    let numbers = NamedVec::new(HashMap::from_iter([("x".to_string(), 1)]), vec![3, 4]);
+    assert_eq!(Some(4), numbers.at(1));
+    assert_eq!(Some([2]), numbers.bounds());
+}
+
+// runs_over
+#[test]
+fn runs_over_coalesces_equal_values() {
+    let vec = vec![1, 1, 1, 2, 2, 3];
+    assert_eq!(
+        vec![(3, 1), (2, 2), (1, 3)],
+        vec.runs_over(0..6).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(3, 1), (2, 2), (1, 3)],
+        vec.runs_over(0..10).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(1, 3), (2, 2), (3, 1)],
+        vec.runs_over((0..6).rev()).collect::<Vec<_>>()
+    );
+    assert_eq!(0, vec.runs_over(6..10).count());
+
+    // holes break runs
+    let sparse = std::collections::BTreeMap::from_iter([(0, 5), (1, 5), (3, 5), (4, 6), (6, 6)]);
+    assert_eq!(
+        vec![(2, 5), (1, 5), (1, 6), (1, 6)],
+        sparse.runs_over(0..8).collect::<Vec<_>>()
+    );
+
+    let uniform = ScalarAsVec(4);
+    assert_eq!(
+        vec![(100, 4)],
+        uniform.runs_over(0..100).collect::<Vec<_>>()
+    );
+}
+
+// unsigned indices
+#[test]
+fn u32_and_u64_indices() {
+    let vec = vec![10, 20, 30];
+    let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+
+    let i: u32 = 1;
+    let k: u64 = 2;
+    assert_eq!(Some(20), vec.at(UIndex(i)));
+    assert_eq!(Some(&30), vec.ref_at(UIndex(k)));
+    assert_eq!(None, vec.at(UIndex(3u32)));
+    assert_eq!(Some(6), matrix.at(UIndex((1u32, 2u32))));
+    assert_eq!(Some(&5), matrix.ref_at(UIndex((1u64, 1u64))));
+    assert_eq!(Some(4), matrix.at(UIndex([1u32, 0])));
+
+    // values beyond usize::MAX on 32-bit targets saturate rather than truncate
+    let huge = u64::MAX;
+    assert_eq!(usize::MAX, UIndex(huge).into_index()[0]);
+    assert_eq!(None, vec.at(UIndex(huge)));
+    assert_eq!(None, matrix.at(UIndex((0, huge))));
+    assert_eq!(Some(7), ScalarAsVec(7).at(UIndex(huge)));
+
+    let indices = [2u32, 0, 5].map(UIndex);
+    assert_eq!(
+        vec![30, 10],
+        vec.iter_over(indices.into_iter())
+            .flatten()
+            .collect::<Vec<_>>()
+    );
+}
+
+// scratch
+#[test]
+fn scratch_fun_reuses_buffer() {
+    use std::cell::Cell;
+
+    let capacities = Cell::new(vec![]);
+    let digits = ScratchFun::new(|i: usize, buffer: &mut Vec<u8>| {
+        buffer.clear();
+        buffer.extend(i.to_string().bytes().map(|b| b - b'0'));
+        let mut seen = capacities.take();
+        seen.push((buffer.as_ptr() as usize, buffer.capacity()));
+        capacities.set(seen);
+        buffer.iter().map(|d| *d as usize).max()
+    });
+
+    assert_eq!(Some(9), digits.at(19283));
+    assert_eq!(Some(7), digits.at(7));
+    assert_eq!(Some(5), digits.at(12345));
+    assert_eq!(Some(0), digits.at(0));
+
+    // the first call allocates; the following calls reuse the same buffer
+    let seen = capacities.take();
+    assert_eq!(4, seen.len());
+    assert!(seen.iter().all(|x| *x == seen[0]));
+
+    let sum: usize = digits.iter_over(0..20).flatten().sum();
+    assert_eq!(45 + 46, sum);
+
+    // scratch is per thread
+    let handle = std::thread::spawn(|| {
+        let len = ScratchFun::new(|i: usize, buffer: &mut Vec<u8>| {
+            let reused = buffer.capacity() > 0;
+            buffer.resize(i, 0);
+            Some(reused)
+        });
+        len.at(3)
+    });
+    assert_eq!(Ok(Some(false)), handle.join().map_err(|_| ()));
+}
+
+// slice view
+#[test]
+fn slice_vec_rebased_window() {
+    let vec: Vec<i32> = (0..10).map(|i| i * i).collect();
+
+    let view = vec.slice_vec(3..7);
+    assert_eq!(4, view.len());
+    assert_eq!(Some([4]), view.bounds());
+    for i in 0..4 {
+        assert_eq!(vec.at(i + 3), view.at(i));
+        assert_eq!(vec.ref_at(i + 3), view.ref_at(i));
+    }
+    assert_eq!(None, view.at(4));
+    assert_eq!(None, view.at(usize::MAX));
+
+    assert_eq!(
+        vec![9, 16, 25, 36],
+        view.iter_over(0..100).flatten().collect::<Vec<_>>()
+    );
+
+    // beyond the inner vector
+    let view = vec.slice_vec(8..12);
+    assert_eq!(Some([4]), view.bounds());
+    assert_eq!(
+        vec![64, 81],
+        view.iter_over(0..4).flatten().collect::<Vec<_>>()
+    );
+
+    let empty = vec.slice_vec(5..5);
+    assert!(empty.is_empty());
+    assert_eq!(None, empty.at(0));
+
+    let nested = view.slice_vec(1..3);
+    assert_eq!(Some(81), nested.at(0));
+    assert_eq!(None, nested.at(1));
+}
+
+// stream
+#[test]
+fn stream_vec_in_and_out_of_order() {
+    use std::cell::Cell;
+
+    let num_pulled = Cell::new(0);
+    let stream = StreamVec::new([5, 6, 7, 8, 9].into_iter().inspect(|_| {
+        num_pulled.set(num_pulled.get() + 1);
+    }));
+    assert_eq!(0, stream.num_cached());
+
+    // in order
+    assert_eq!(Some(5), stream.at(0));
+    assert_eq!(Some(6), stream.at(1));
+    assert_eq!(2, num_pulled.get());
+
+    // far ahead buffers the intermediate values
+    assert_eq!(Some(9), stream.at(4));
+    assert_eq!(5, stream.num_cached());
+    assert_eq!(5, num_pulled.get());
+
+    // already consumed indices are served from the cache
+    assert_eq!(Some(7), stream.at(2));
+    assert_eq!(Some(5), stream.at(0));
+    assert_eq!(5, num_pulled.get());
+
+    // beyond the end of the stream
+    assert_eq!(None, stream.at(5));
+    assert_eq!(None, stream.at(100));
+    assert_eq!(None, stream.at(usize::MAX));
+    assert_eq!(5, stream.num_cached());
+
+    assert_eq!(35, stream.iter_over(0..10).flatten().sum::<i32>());
+
+    let lines = StreamVec::new("a\nbb\nccc".lines().map(|x| x.len()));
+    assert_eq!(Some(3), lines.at(2));
+    assert_eq!(Some(1), lines.at(0));
+}
+
+// sum range
+#[test]
+fn sum_range_matches_flatten_sum() {
+    fn flatten_sum<T: Clone + Copy + std::iter::Sum<T>, V: FunVec<1, T>>(
+        vec: &V,
+        range: std::ops::Range<usize>,
+    ) -> T {
+        vec.iter_over(range).flatten().sum()
+    }
+
+    let ints: Vec<i32> = (0..100).map(|i| (i % 23) - 11).collect();
+    let floats: Vec<f32> = (0..100).map(|i| (i % 17) as f32 * 0.5).collect();
+    #[allow(clippy::reversed_empty_ranges)]
+    let ranges = [0..100, 10..20, 0..0, 42..41, 90..150, 100..200, 250..300];
+
+    for range in ranges {
+        assert_eq!(
+            flatten_sum(&ints, range.clone()),
+            ints.sum_range(range.clone())
+        );
+        assert_eq!(
+            flatten_sum(&ints.as_slice(), range.clone()),
+            ints.as_slice().sum_range(range.clone())
+        );
+        assert_eq!(
+            flatten_sum(&floats, range.clone()),
+            floats.sum_range(range.clone())
+        );
+    }
+
+    let array = [1, 2, 3, 4];
+    assert_eq!(9, array.sum_range(1..10));
+    assert_eq!(0, array.sum_range(4..10));
+
+    let sparse: std::collections::HashMap<usize, i32> =
+        std::collections::HashMap::from_iter([(3, 4), (120, 7)]);
+    assert_eq!(11, sparse.sum_range(0..200));
+    assert_eq!(4, sparse.sum_range(0..100));
+}
+
+// iter over set
+#[test]
+fn btreemap_iter_over_set_matches_probing() {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    let map: BTreeMap<usize, i64> = (0..200).map(|i| (3 * i, i as i64 - 50)).collect();
+    let sets = [
+        BTreeSet::new(),
+        (0..300).map(|i| 2 * i).collect(),
+        (1000..1010).collect(),
+        map.keys().copied().collect(),
+        BTreeSet::from_iter([0, 1, 2, 597, 598, 599, 600]),
+    ];
+
+    for set in &sets {
+        let probed: Vec<_> = set
+            .iter()
+            .filter_map(|i| map.at(*i).map(|x| (i, x)))
+            .collect();
+        let intersected: Vec<_> = map.iter_over_set(set).collect();
+        assert_eq!(probed, intersected);
+    }
+
+    let map2: BTreeMap<(usize, usize), char> =
+        BTreeMap::from_iter([((0, 1), 'a'), ((1, 0), 'b'), ((2, 2), 'c')]);
+    let set2 = BTreeSet::from_iter([(0, 0), (1, 0), (2, 2), (3, 0)]);
+    let found: Vec<_> = map2.iter_over_set(&set2).collect();
+    assert_eq!(vec![(&(1, 0), 'b'), (&(2, 2), 'c')], found);
+}
+
+// versioned
+#[test]
+fn versioned_historical_values() {
+    use std::collections::HashMap;
+
+    let history = HashMap::from_iter([
+        (0, vec![(1, 10), (4, 40), (9, 90)]),
+        (1, vec![(7, 700), (2, 200)]),
+        (3, vec![(5, 5), (5, 55)]),
+        (4, vec![]),
+    ]);
+
+    let at_version = |version| {
+        let snapshot = Versioned::new(&history, version);
+        (0..5).map(|i| snapshot.at(i)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(vec![None; 5], at_version(0));
+    assert_eq!(vec![Some(10), None, None, None, None], at_version(1));
+    assert_eq!(vec![Some(10), Some(200), None, None, None], at_version(3));
+    assert_eq!(
+        vec![Some(40), Some(200), None, Some(55), None],
+        at_version(5)
+    );
+    assert_eq!(
+        vec![Some(40), Some(700), None, Some(55), None],
+        at_version(8)
+    );
+    assert_eq!(
+        vec![Some(90), Some(700), None, Some(55), None],
+        at_version(u64::MAX)
+    );
+
+    let snapshot = Versioned::new(&history, 4);
+    assert_eq!(Some(&40), snapshot.ref_at(0));
+    assert_eq!(240, snapshot.iter_over(0..10).flatten().sum());
+
+    let earlier = snapshot.with_version(3);
+    assert_eq!(3, earlier.version());
+    assert_eq!(Some(10), earlier.at(0));
+    assert_eq!(Some(40), snapshot.at(0));
+}
@@ -0,0 +1,1825 @@
+use orx_funvec::*;
+
+// dense f64 matrix
+fn assert_matches_nested<V: FunVec<2, f64>>(vec: &V, nested: &[Vec<f64>]) {
+    for (i, row) in nested.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            assert_eq!(Some(*value), vec.at([i, j]));
+        }
+    }
+}
+
+#[test]
+fn dense_f64_mat_row_major() {
+    let nested = vec![vec![0.0, 1.0, 2.0], vec![10.0, 11.0, 12.0]];
+    let data: Vec<_> = nested.iter().flatten().copied().collect();
+    let mat = DenseF64Mat::new(&data, 2, 3, true);
+
+    assert_matches_nested(&mat, &nested);
+    assert_eq!(Some(&12.0), mat.ref_at((1, 2)));
+    assert_eq!(Some([2, 3]), mat.bounds());
+
+    assert_eq!(None, mat.at([2, 0]));
+    assert_eq!(None, mat.at([0, 3]));
+    assert_eq!(None, mat.ref_at([2, 3]));
+}
+
+#[test]
+fn dense_f64_mat_col_major() {
+    let nested = vec![vec![0.0, 1.0, 2.0], vec![10.0, 11.0, 12.0]];
+    let data = vec![0.0, 10.0, 1.0, 11.0, 2.0, 12.0];
+    let mat = DenseF64Mat::new(&data, 2, 3, false);
+
+    assert_matches_nested(&mat, &nested);
+
+    assert_eq!(None, mat.at([2, 0]));
+    assert_eq!(None, mat.at([0, 3]));
+    assert_eq!(None, mat.ref_at([2, 3]));
+}
+
+#[test]
+fn dense_f64_mat_short_buffer() {
+    let data = vec![0.0, 1.0, 2.0];
+    let mat = DenseF64Mat::new(&data, 2, 2, true);
+
+    assert_eq!(Some(2.0), mat.at([1, 0]));
+    assert_eq!(None, mat.at([1, 1]));
+}
+
+#[test]
+fn dense_f64_mat_huge_dimensions() {
+    let data = [1.0, 2.0, 3.0, 4.0];
+    let matrix = DenseF64Mat::new(&data, usize::MAX, usize::MAX, true);
+
+    assert_eq!(Some(2.0), matrix.at((0, 1)));
+    assert_eq!(None, matrix.at((2, 0)));
+    assert_eq!(None, matrix.at((usize::MAX - 1, usize::MAX - 1)));
+
+    let matrix = DenseF64Mat::new(&data, usize::MAX, usize::MAX, false);
+    assert_eq!(Some(2.0), matrix.at((1, 0)));
+    assert_eq!(None, matrix.at((0, 2)));
+}
+
+// row cached
+#[test]
+fn row_cached_hashmap_of_vecs() {
+    use std::collections::HashMap;
+
+    let matrix = HashMap::from_iter([(0, vec![1, 2, 3]), (1, vec![10, 20]), (3, vec![100])]);
+
+    let mut row = RowCached::new(&matrix, 0);
+    for i in 0..5 {
+        assert_eq!(i, row.row_index());
+        for j in 0..4 {
+            assert_eq!(matrix.at([i, j]), row.at(j));
+            assert_eq!(matrix.ref_at([i, j]), row.ref_at(j));
+        }
+        row.advance();
+    }
+
+    row.set_row(1);
+    assert_eq!(30, row.iter_over(0..10).flatten().sum());
+}
+
+#[test]
+fn row_cached_advance_saturates() {
+    use std::collections::HashMap;
+
+    let matrix = HashMap::from_iter([(usize::MAX, vec![7, 8])]);
+
+    let mut row = RowCached::new(&matrix, usize::MAX - 1);
+    assert_eq!(None, row.at(0));
+
+    row.advance();
+    assert_eq!(usize::MAX, row.row_index());
+    assert_eq!(Some(8), row.at(1));
+
+    row.advance();
+    assert_eq!(usize::MAX, row.row_index());
+    assert_eq!(Some(7), row.at(0));
+}
+
+// ndarray
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_broadcast_row() {
+    use ndarray::Array2;
+
+    let row = Array2::from_shape_vec((1, 3), vec![1, 2, 3]).expect("valid shape");
+    let broadcast = row.broadcast((4, 3)).expect("broadcastable");
+
+    for i in 0..4 {
+        for j in 0..3 {
+            assert_eq!(Some(j as i32 + 1), broadcast.at((i, j)));
+            assert_eq!(Some(&(j as i32 + 1)), broadcast.ref_at([i, j]));
+        }
+        assert_eq!(None, broadcast.at((i, 3)));
+    }
+    assert_eq!(None, broadcast.at((4, 0)));
+    assert_eq!(Some([4, 3]), broadcast.bounds());
+
+    assert_eq!(
+        4 * 6,
+        broadcast
+            .iter_over((0..5).flat_map(|i| (0..5).map(move |j| (i, j))))
+            .flatten()
+            .sum()
+    );
+}
+
+// sum_over_with
+#[test]
+fn sum_over_with_index_dependent_holes() {
+    let matrix = vec![vec![1, 2], vec![3]];
+    let pairs = [(0, 0), (0, 1), (1, 0), (1, 1), (2, 5)];
+    assert_eq!(
+        1 + 2 + 3 + 11 + 25,
+        matrix.sum_over_with(pairs.iter().copied(), |[i, j]| (10 * i + j) as i32)
+    );
+}
+
+// generic array
+#[cfg(any(feature = "impl_all", feature = "impl_generic_array"))]
+#[test]
+fn generic_array_of_rows() {
+    use generic_array::GenericArray;
+
+    let matrix = GenericArray::from_array([vec![1, 2], vec![3, 4, 5]]);
+    assert_eq!(Some(2), matrix.at((0, 1)));
+    assert_eq!(Some(&5), matrix.ref_at([1, 2]));
+    assert_eq!(None, matrix.at((0, 2)));
+    assert_eq!(None, matrix.at((2, 0)));
+    assert_eq!(
+        15,
+        matrix
+            .iter_over([(0, 0), (0, 1), (1, 0), (1, 1), (1, 2), (2, 2)].into_iter())
+            .flatten()
+            .sum()
+    );
+}
+
+// to_sorted_pairs_over
+#[test]
+fn to_sorted_pairs_over_is_backend_independent() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let entries = [((2, 1), 21), ((0, 3), 3), ((1, 1), 11), ((0, 0), 0)];
+    let hashmap: HashMap<(usize, usize), i32> = HashMap::from_iter(entries);
+    let btreemap: BTreeMap<(usize, usize), i32> = BTreeMap::from_iter(entries);
+
+    let indices = || (0..3).rev().flat_map(|i| (0..4).map(move |j| (i, j)));
+
+    let expected = vec![([0, 0], 0), ([0, 3], 3), ([1, 1], 11), ([2, 1], 21)];
+    assert_eq!(expected, hashmap.to_sorted_pairs_over(indices()));
+    assert_eq!(expected, btreemap.to_sorted_pairs_over(indices()));
+}
+
+// closures
+fn val_assert_distances<V: FunVec<2, i32>>(vec: &V) {
+    // (i, j) -> 10 * i + j for i, j < 3
+
+    assert_eq!(Some(12), vec.at((1, 2)));
+    assert_eq!(Some(20), vec.at([2, 0]));
+    assert_eq!(None, vec.at((3, 0)));
+
+    assert_eq!(
+        90 + 9,
+        vec.iter_over((0..4).flat_map(|i| (0..4).map(move |j| (i, j))))
+            .flatten()
+            .sum()
+    );
+}
+
+#[test]
+fn closure_oneof_captured_2d() {
+    use orx_closure::{Capture, ClosureOneOf2, ClosureOneOf3, ClosureOneOf4};
+
+    let n = 3;
+    let closure = Capture(n).fun(|n, (i, j): (usize, usize)| match i < *n && j < *n {
+        true => Some((10 * i + j) as i32),
+        false => None,
+    });
+    val_assert_distances(&closure);
+
+    let closure_oneof2: ClosureOneOf2<usize, String, (usize, usize), _> =
+        closure.clone().into_oneof2_var1();
+    val_assert_distances(&closure_oneof2);
+
+    let closure_oneof3: ClosureOneOf3<usize, String, Vec<usize>, (usize, usize), _> =
+        closure.clone().into_oneof3_var1();
+    val_assert_distances(&closure_oneof3);
+    assert_eq!(Some(21), closure_oneof3.at((2, 1)));
+
+    let closure_oneof4: ClosureOneOf4<usize, String, Vec<usize>, (), (usize, usize), _> =
+        closure.clone().into_oneof4_var1();
+    val_assert_distances(&closure_oneof4);
+}
+
+// either
+#[cfg(any(feature = "impl_all", feature = "impl_either"))]
+#[test]
+fn either_rows_with_empty_vec_marker() {
+    use either::Either;
+
+    let matrix: Vec<Either<Vec<i32>, EmptyVec<i32>>> = vec![
+        Either::Left(vec![1, 2, 3]),
+        Either::Right(EmptyVec::new()),
+        Either::Left(vec![7]),
+    ];
+
+    assert_eq!(Some(2), matrix.at((0, 1)));
+    assert_eq!(Some(&7), matrix.ref_at([2, 0]));
+    for j in 0..10 {
+        assert_eq!(None, matrix.at((1, j)));
+        assert_eq!(None, matrix.ref_at((1, j)));
+    }
+    assert_eq!(None, matrix.at((3, 0)));
+
+    let all = (0..4).flat_map(|i| (0..4).map(move |j| (i, j)));
+    assert_eq!(13, matrix.iter_over(all).flatten().sum());
+
+    assert_eq!(Some([0]), matrix[1].bounds());
+}
+
+// ndarray huge indices
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_huge_indices() {
+    use ndarray::Array2;
+
+    let arr = Array2::from_shape_fn((2, 3), |(i, j)| i + j);
+    for index in [(usize::MAX, 0), (0, usize::MAX), (usize::MAX, usize::MAX)] {
+        assert_eq!(None, arr.at(index));
+        assert_eq!(None, arr.ref_at(index));
+        assert_eq!(None, arr.view().at(index));
+        assert_eq!(None, arr.view().ref_at(index));
+    }
+}
+
+// array zip
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_zip() {
+    use ndarray::Array2;
+
+    let a = Array2::from_shape_fn((2, 3), |(i, j)| (10 * i + j) as i32);
+    let b = Array2::from_shape_fn((2, 3), |(i, j)| -((10 * i + j) as i32));
+
+    let zipped = ArrayZip2::try_new(&a, &b).expect("same shape");
+    assert_eq!(Some((12, -12)), zipped.at((1, 2)));
+    assert_eq!(None, zipped.at((2, 0)));
+    assert_eq!(None, zipped.at((0, 3)));
+    assert_eq!(Some([2, 3]), zipped.bounds());
+
+    let all = (0..3).flat_map(|i| (0..4).map(move |j| (i, j)));
+    assert!(zipped.iter_over(all).flatten().all(|(x, y)| x == -y));
+
+    let c = Array2::from_elem((2, 3), true);
+    let zipped = ArrayZip3::try_new(&a, &b, &c).expect("same shape");
+    assert_eq!(Some((1, -1, true)), zipped.at((0, 1)));
+    assert_eq!(None, zipped.at((5, 5)));
+
+    let other = Array2::from_elem((3, 2), 0);
+    assert!(ArrayZip2::try_new(&a, &other).is_none());
+    assert!(ArrayZip3::try_new(&a, &b, &other).is_none());
+}
+
+// debug_grid
+#[test]
+fn debug_grid() {
+    use std::collections::HashMap;
+
+    let matrix: HashMap<(usize, usize), i32> =
+        HashMap::from_iter([((0, 0), 1), ((0, 2), -3), ((2, 1), 100)]);
+
+    let expected = ["  1   .  -3", "  .   .   .", "  . 100   ."].join("\n");
+    assert_eq!(expected, matrix.debug_grid(3, 3));
+
+    assert_eq!("1 .", matrix.debug_grid(1, 2));
+    assert_eq!("", matrix.debug_grid(0, 3));
+
+    assert_eq!("7 7\n7 7", ScalarAsVec(7).debug_grid(2, 2));
+    assert_eq!("1 2 .\n3 . .", vec![vec![1, 2], vec![3]].debug_grid(2, 3));
+}
+
+#[test]
+fn debug_grid_non_ascii() {
+    let chars = vec![vec!['é', 'a'], vec!['ü']];
+    assert_eq!("é a\nü .", chars.debug_grid(2, 2));
+
+    let words = vec![vec!["αβγ", "x"], vec!["ab"]];
+    assert_eq!("αβγ   x\n ab   .", words.debug_grid(2, 2));
+}
+
+// transform
+#[test]
+fn transform_equals_two_step_chain() {
+    let matrix = vec![vec![1, 2, 3], vec![4, 5], vec![6]];
+    let remap = |[i, j]: [usize; 2]| [j, i];
+    let map = |x: i32| 2 * x - 1;
+
+    let fused = matrix.clone().transform(remap, map);
+    let chained = matrix
+        .clone()
+        .transform(remap, |x| x)
+        .transform(|idx| idx, map);
+
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = matrix.at(remap([i, j])).map(map);
+            assert_eq!(expected, fused.at([i, j]));
+            assert_eq!(expected, chained.at((i, j)));
+        }
+    }
+
+    assert_eq!(Some(9), fused.at([1, 1]));
+    assert_eq!(Some(11), fused.at([0, 2]));
+    assert_eq!(Some(5), fused.at([2, 0]));
+    assert_eq!(None, fused.at([1, 2]));
+}
+
+// lazy map
+#[test]
+fn lazy_map_calls_closures_only_on_hit() {
+    use std::{cell::Cell, collections::HashMap};
+
+    let calls = Cell::new(0);
+    let count_and = |value: i32| {
+        let calls = &calls;
+        move || {
+            calls.set(calls.get() + 1);
+            value
+        }
+    };
+
+    let cells = HashMap::from_iter([((0, 1), count_and(10)), ((2, 3), count_and(20))]);
+    let matrix = LazyMap(cells);
+    assert_eq!(0, calls.get());
+
+    assert_eq!(None, matrix.at((1, 0)));
+    assert_eq!(None, matrix.at([5, 5]));
+    assert_eq!(0, calls.get());
+
+    assert_eq!(Some(10), matrix.at((0, 1)));
+    assert_eq!(1, calls.get());
+
+    assert_eq!(Some(20), matrix.at([2, 3]));
+    assert_eq!(Some(20), matrix.at([2, 3]));
+    assert_eq!(3, calls.get());
+
+    let sum: i32 = matrix
+        .iter_over((0..4).flat_map(|i| (0..4).map(move |j| (i, j))))
+        .flatten()
+        .sum();
+    assert_eq!(30, sum);
+    assert_eq!(5, calls.get());
+}
+
+// axis slice
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_axis_slice() {
+    use ndarray::{s, Array3};
+
+    let tensor = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (100 * i + 10 * j + k) as i32);
+
+    for index in 0..2 {
+        let slice = AxisSlice::new(&tensor, 0, index);
+        let expected = tensor.slice(s![index, .., ..]);
+
+        assert_eq!(Some([3, 4]), slice.bounds());
+        for i in 0..4 {
+            for j in 0..5 {
+                assert_eq!(expected.get((i, j)).copied(), slice.at((i, j)));
+                assert_eq!(expected.get((i, j)), slice.ref_at([i, j]));
+            }
+        }
+    }
+
+    let slice = AxisSlice::new(&tensor, 1, 2);
+    assert_eq!(Some([2, 4]), slice.bounds());
+    assert_eq!(Some(123), slice.at((1, 3)));
+
+    let out_of_range = AxisSlice::new(&tensor, 0, 2);
+    assert_eq!(None, out_of_range.at((0, 0)));
+    assert_eq!(Some([0, 0]), out_of_range.bounds());
+
+    let invalid_axis = AxisSlice::new(&tensor, 3, 0);
+    assert_eq!(None, invalid_axis.at((0, 0)));
+}
+
+// reduce_rows
+#[test]
+fn reduce_rows_sums() {
+    let matrix = vec![vec![1, 2, 3, 4], vec![-5, 6], vec![], vec![7, 8, 9, 10]];
+
+    let manual: Vec<i32> = matrix.iter().map(|row| row.iter().sum()).collect();
+    assert_eq!(manual, matrix.reduce_rows(0..4, 0..4, 0, |x, y| x + y));
+
+    assert_eq!(
+        vec![6, 0, 27],
+        matrix.reduce_rows(1..4, 1..4, 0, |x, y| x + y)
+    );
+    assert_eq!(
+        vec![4, -5, 1, 70],
+        matrix.reduce_rows(0..4, [0, 3].into_iter(), 1, |x, y| x * y)
+    );
+    assert!(matrix.reduce_rows(2..2, 0..4, 0, |x, y| x + y).is_empty());
+}
+
+// iter_all_2d
+#[test]
+fn iter_all_2d_jagged() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let jagged = vec![vec![1, 2, 3], vec![], vec![4], vec![5, 6]];
+    let expected = vec![
+        (0, 0, &1),
+        (0, 1, &2),
+        (0, 2, &3),
+        (2, 0, &4),
+        (3, 0, &5),
+        (3, 1, &6),
+    ];
+
+    let all: Vec<_> = jagged.iter_all_2d().collect();
+    assert_eq!(expected, all);
+    for (i, j, x) in all {
+        assert_eq!(Some(x), jagged.ref_at((i, j)));
+    }
+
+    let arrays = [[1, 2], [3, 4]];
+    let all: Vec<_> = arrays.iter_all_2d().map(|(i, j, x)| (i, j, *x)).collect();
+    assert_eq!(vec![(0, 0, 1), (0, 1, 2), (1, 0, 3), (1, 1, 4)], all);
+
+    let hashmap = HashMap::from_iter([(3, vec![5, 6]), (0, vec![1, 2, 3]), (2, vec![4])]);
+    assert_eq!(expected, hashmap.iter_all_2d().collect::<Vec<_>>());
+
+    let btreemap = BTreeMap::from_iter([(3, vec![5, 6]), (0, vec![1, 2, 3]), (2, vec![4])]);
+    assert_eq!(expected, btreemap.iter_all_2d().collect::<Vec<_>>());
+}
+
+// masked
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_masked_checkerboard() {
+    use ndarray::Array2;
+
+    let values = Array2::from_shape_fn((3, 4), |(i, j)| (10 * i + j) as i32);
+    let mask = Array2::from_shape_fn((3, 4), |(i, j)| (i + j) % 2 == 0);
+    let masked = Masked2::new(&values, &mask);
+
+    for i in 0..4 {
+        for j in 0..5 {
+            let expected = match i < 3 && j < 4 && (i + j) % 2 == 0 {
+                true => Some((10 * i + j) as i32),
+                false => None,
+            };
+            assert_eq!(expected, masked.at((i, j)));
+            assert_eq!(expected.as_ref(), masked.ref_at([i, j]));
+        }
+    }
+
+    let smaller_mask = Array2::from_elem((1, 1), true);
+    let masked = Masked2::new(&values, &smaller_mask);
+    assert_eq!(Some(0), masked.at((0, 0)));
+    assert_eq!(None, masked.at((1, 1)));
+}
+
+// integral image
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_integral_image_rect_sums() {
+    let matrix: Vec<Vec<i64>> = (0..4)
+        .map(|i| (0..5).map(|j| (3 * i + 7 * j) % 11 - 4).collect())
+        .collect();
+    let sat = IntegralImage::from_funvec(&matrix, 4, 5);
+    assert_eq!((4, 5), sat.dimensions());
+    assert_eq!(Some([4, 5]), sat.bounds());
+
+    let rectangles = [
+        ((0, 0), (4, 5)),
+        ((0, 0), (1, 1)),
+        ((1, 2), (3, 5)),
+        ((2, 0), (4, 3)),
+        ((3, 4), (4, 5)),
+        ((2, 2), (2, 4)),
+    ];
+    for ((r0, c0), (r1, c1)) in rectangles {
+        let indices = (r0..r1).flat_map(|i| (c0..c1).map(move |j| (i, j)));
+        let brute_force: i64 = matrix.iter_over(indices).flatten().sum();
+        assert_eq!(brute_force, sat.rect_sum((r0, c0), (r1, c1)));
+    }
+
+    assert_eq!(sat.rect_sum((0, 0), (4, 5)), sat.rect_sum((0, 0), (10, 10)));
+    assert_eq!(Some(matrix[0][0]), sat.at((0, 0)));
+    assert_eq!(Some(&sat.rect_sum((0, 0), (2, 3))), sat.ref_at([1, 2]));
+    assert_eq!(None, sat.at((4, 0)));
+}
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_integral_image_unsigned_rect_sums() {
+    let matrix: Vec<Vec<u32>> = vec![vec![100, 0], vec![0, 1]];
+    let sat = IntegralImage::from_funvec(&matrix, 2, 2);
+    assert_eq!(1, sat.rect_sum((1, 1), (2, 2)));
+    assert_eq!(0, sat.rect_sum((1, 0), (2, 1)));
+    assert_eq!(101, sat.rect_sum((0, 0), (2, 2)));
+
+    let matrix: Vec<Vec<u32>> = vec![
+        vec![1_000_000, 2_000_000, 3_000_000],
+        vec![4_000_000, 5, 6],
+        vec![7_000_000, 8, 9],
+    ];
+    let sat = IntegralImage::from_funvec(&matrix, 3, 3);
+    assert_eq!(5 + 6 + 8 + 9, sat.rect_sum((1, 1), (3, 3)));
+    assert_eq!(9, sat.rect_sum((2, 2), (3, 3)));
+    assert_eq!(6 + 9, sat.rect_sum((1, 2), (10, 10)));
+}
+
+// lazy rows
+#[test]
+fn lazy_rows_built_per_access() {
+    use std::cell::RefCell;
+
+    let built = RefCell::new(vec![]);
+    let matrix = LazyRows::new(|i: usize| {
+        built.borrow_mut().push(i);
+        match i < 3 {
+            true => Some(vec![i; i + 1]),
+            false => None,
+        }
+    });
+    assert!(built.borrow().is_empty());
+
+    assert_eq!(Some(2), matrix.at((2, 1)));
+    assert_eq!(None, matrix.at((1, 2)));
+    assert_eq!(None, matrix.at([5, 0]));
+    assert_eq!(vec![2, 1, 5], *built.borrow());
+
+    let sum: usize = matrix
+        .iter_over((0..4).flat_map(|i| (0..4).map(move |j| (i, j))))
+        .flatten()
+        .sum();
+    assert_eq!(2 + 6, sum);
+    assert_eq!(3 + 16, built.borrow().len());
+}
+
+// ndarray to sparse
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_to_sparse() {
+    use ndarray::Array2;
+
+    let arr = Array2::from_shape_fn((10, 10), |(i, j)| match (i * j) % 7 == 3 {
+        true => (i + j) as f64,
+        false => 0.0,
+    });
+    let is_zero = |v: f64| v.abs() < f64::EPSILON;
+
+    let sparse = from_ndarray_sparse(&arr, is_zero);
+
+    let non_zeros = arr.iter().filter(|v| !is_zero(**v)).count();
+    assert_eq!(non_zeros, sparse.len());
+    for ((i, j), v) in arr.indexed_iter() {
+        match is_zero(*v) {
+            true => assert_eq!(None, sparse.at((i, j))),
+            false => assert_eq!(Some(v), sparse.ref_at((i, j))),
+        }
+    }
+
+    let none_empty = from_ndarray_sparse(&arr, |_| false);
+    assert_eq!(100, none_empty.len());
+}
+
+// borrowed dyn Fn
+#[test]
+fn ref_dyn_fn_2d() {
+    let upper_triangular = |(i, j): (usize, usize)| match i <= j && j < 3 {
+        true => Some((i + j) as i32),
+        false => None,
+    };
+    let matrix: &dyn Fn((usize, usize)) -> Option<i32> = &upper_triangular;
+
+    assert_eq!(Some(3), matrix.at((1, 2)));
+    assert_eq!(None, matrix.at([2, 1]));
+    assert_eq!(
+        3 * 4,
+        matrix
+            .iter_over((0..4).flat_map(|i| (0..4).map(move |j| (i, j))))
+            .flatten()
+            .sum::<i32>()
+    );
+}
+
+// ndarray storages
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_any_storage() {
+    use ndarray::{Array1, Array2, CowArray};
+
+    fn sum<V: FunVec<2, i32> + FunVecRef<2, i32>>(matrix: &V) -> i32 {
+        let all = || (0..3).flat_map(|i| (0..4).map(move |j| (i, j)));
+        let sum = matrix.iter_over(all()).flatten().sum();
+        assert_eq!(sum, matrix.ref_iter_over(all()).flatten().sum::<i32>());
+        sum
+    }
+
+    let mut owned = Array2::from_shape_fn((2, 3), |(i, j)| (10 * i + j) as i32);
+    let expected = 36;
+
+    assert_eq!(expected, sum(&owned));
+    assert_eq!(expected, sum(&owned.view()));
+    assert_eq!(expected, sum(&owned.to_shared()));
+    assert_eq!(expected, sum(&CowArray::from(owned.view())));
+    assert_eq!(expected, sum(&owned.view_mut()));
+    assert_eq!(expected, sum(&owned.t()));
+
+    let row = Array1::from_vec(vec![1, 2, 3]);
+    let broadcast = row.broadcast((2, 3)).expect("broadcastable");
+    assert_eq!(12, sum(&broadcast));
+    assert_eq!(Some([2, 3]), broadcast.bounds());
+    assert_eq!(Some([3]), row.view().bounds());
+    assert_eq!(Some(2), row.to_shared().at(1));
+}
+
+// labeled
+#[test]
+fn labeled_columns() {
+    use std::collections::HashMap;
+
+    let labels: HashMap<&str, usize> = HashMap::from_iter([("red", 0), ("green", 1), ("blue", 2)]);
+    let pixels = vec![vec![255, 0, 0], vec![0, 128], vec![]];
+
+    let image = Labeled2::new(pixels, |label: &str| labels.get(label).copied());
+
+    assert_eq!(Some(255), image.at_labeled(0, "red"));
+    assert_eq!(Some(128), image.at_labeled(1, "green"));
+    assert_eq!(None, image.at_labeled(1, "blue"));
+    assert_eq!(None, image.at_labeled(2, "red"));
+    assert_eq!(None, image.at_labeled(0, "alpha"));
+    assert_eq!(None, image.at_labeled(0, ""));
+    assert_eq!(Some(0), image.inner().at((0, 2)));
+}
+
+// outer product
+#[test]
+fn outer_product_of_vecs() {
+    use std::collections::HashMap;
+
+    let a = vec![1, 2, 3];
+    let b = vec![-1, 0, 5, 7];
+    let product = OuterProduct::new(a.clone(), b.clone());
+
+    for i in 0..4 {
+        for j in 0..5 {
+            let expected = match (a.get(i), b.get(j)) {
+                (Some(x), Some(y)) => Some(x * y),
+                _ => None,
+            };
+            assert_eq!(expected, product.at((i, j)));
+        }
+    }
+    assert_eq!(Some([3, 4]), product.bounds());
+
+    let sparse = HashMap::from_iter([(1, 2)]);
+    let product = OuterProduct::new(sparse, b);
+    assert_eq!(Some(14), product.at([1, 3]));
+    assert_eq!(None, product.at([0, 3]));
+    assert_eq!(None, product.bounds());
+}
+
+// auto mat
+#[test]
+fn auto_mat_representation_independent() {
+    let all = || (0..6).flat_map(|i| (0..6).map(move |j| (i, j)));
+
+    let dense_entries: Vec<_> = all()
+        .filter(|(i, j)| i < &5 && j < &5 && (i + j) % 3 != 0)
+        .map(|(i, j)| ((i, j), (10 * i + j) as i32))
+        .collect();
+    let sparse_entries = vec![((0, 4), 4), ((3, 1), 31), ((4, 4), 44)];
+
+    for entries in [dense_entries, sparse_entries] {
+        let dense = AutoMat::from_entries(5, 5, entries.clone(), 0.0);
+        let sparse = AutoMat::from_entries(5, 5, entries.clone(), 1.1);
+        let auto = AutoMat::from_entries(5, 5, entries.clone(), 0.5);
+        assert!(dense.is_dense());
+        assert!(!sparse.is_dense());
+
+        for (i, j) in all() {
+            let expected = entries.iter().find(|x| x.0 == (i, j)).map(|x| x.1);
+            assert_eq!(expected, dense.at((i, j)));
+            assert_eq!(expected, sparse.at((i, j)));
+            assert_eq!(expected, auto.at([i, j]));
+            assert_eq!(expected.as_ref(), auto.ref_at([i, j]));
+        }
+    }
+
+    let dense = AutoMat::from_entries(2, 2, [((0, 0), 1), ((1, 1), 2), ((1, 1), 3)], 0.5);
+    assert!(dense.is_dense());
+    assert_eq!(Some(3), dense.at((1, 1)));
+
+    let out_of_bounds = AutoMat::from_entries(2, 2, [((0, 0), 1), ((5, 5), 2)], 0.0);
+    assert_eq!(None, out_of_bounds.at((5, 5)));
+
+    let empty: AutoMat<i32> = AutoMat::from_entries(0, 0, [], 0.0);
+    assert!(!empty.is_dense());
+}
+
+#[test]
+fn auto_mat_huge_dimensions_sparse() {
+    let entries = [
+        ((0, 0), 1),
+        ((usize::MAX - 1, 7), 2),
+        ((3, usize::MAX - 1), 3),
+    ];
+    let matrix = AutoMat::from_entries(usize::MAX, usize::MAX, entries, 0.0);
+    assert!(!matrix.is_dense());
+    assert_eq!(Some(1), matrix.at((0, 0)));
+    assert_eq!(Some(2), matrix.at((usize::MAX - 1, 7)));
+    assert_eq!(Some(&3), matrix.ref_at((3, usize::MAX - 1)));
+    assert_eq!(None, matrix.at((1, 1)));
+
+    let matrix = AutoMat::from_entries(usize::MAX, 2, [((5, 1), 'x')], 0.0);
+    assert!(!matrix.is_dense());
+    assert_eq!(Some('x'), matrix.at((5, 1)));
+}
+
+#[test]
+fn auto_mat_try_from_entries() {
+    type Entry = Result<((usize, usize), i32), String>;
+
+    let entries: Vec<Entry> = vec![Ok(((0, 0), 1)), Ok(((1, 1), 2))];
+    let matrix = AutoMat::try_from_entries(2, 2, entries, 0.5);
+    assert!(matrix.is_ok());
+    if let Ok(matrix) = matrix {
+        assert_eq!(Some(1), matrix.at((0, 0)));
+        assert_eq!(Some(&2), matrix.ref_at((1, 1)));
+        assert_eq!(None, matrix.at((0, 1)));
+    }
+
+    let entries: Vec<Entry> = vec![
+        Ok(((0, 0), 1)),
+        Err("first".to_string()),
+        Ok(((1, 1), 2)),
+        Err("second".to_string()),
+    ];
+    let mut num_consumed = 0;
+    let consumed = entries.into_iter().inspect(|_| num_consumed += 1);
+    let matrix = AutoMat::try_from_entries(2, 2, consumed, 0.5);
+    assert_eq!(Some("first".to_string()), matrix.err());
+    assert_eq!(2, num_consumed);
+}
+
+// coo
+#[test]
+fn coo_triplets() {
+    let triplets = vec![(3, 1, 31), (0, 0, 1), (1, 2, 12), (0, 0, 2), (2, 2, 22)];
+
+    let unsorted = Coo2::new(&triplets);
+    assert!(!unsorted.is_sorted());
+    assert_eq!(Some(31), unsorted.at((3, 1)));
+    assert_eq!(Some(&12), unsorted.ref_at((1, 2)));
+    assert_eq!(Some(2), unsorted.at((0, 0))); // last wins
+    assert_eq!(None, unsorted.at((2, 1)));
+    assert_eq!(None, unsorted.at((5, 5)));
+
+    let mut sorted_triplets = triplets.clone();
+    sorted_triplets.sort_by_key(|t| (t.0, t.1)); // stable sort keeps the order of duplicates
+    let sorted = Coo2::new_sorted(&sorted_triplets);
+    assert!(sorted.is_sorted());
+
+    for i in 0..5 {
+        for j in 0..5 {
+            assert_eq!(unsorted.at((i, j)), sorted.at((i, j)));
+            assert_eq!(unsorted.ref_at([i, j]), sorted.ref_at([i, j]));
+        }
+    }
+
+    let empty: Coo2<i32> = Coo2::new_sorted(&[]);
+    assert_eq!(None, empty.at((0, 0)));
+}
+
+// pool2
+#[test]
+fn pool2_max_and_average() {
+    let matrix = vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+        vec![13, 14, 15, 16],
+    ];
+
+    let max = |x: &[i32]| x.iter().copied().max().unwrap_or(0);
+    let avg = |x: &[i32]| match x.len() {
+        0 => 0,
+        n => x.iter().sum::<i32>() / n as i32,
+    };
+
+    assert_eq!(
+        vec![vec![6, 8], vec![14, 16]],
+        matrix.pool2(4, 4, (2, 2), max)
+    );
+    assert_eq!(
+        vec![vec![3, 5], vec![11, 13]],
+        matrix.pool2(4, 4, (2, 2), avg)
+    );
+
+    assert_eq!(
+        vec![vec![11, 12], vec![15, 16]],
+        matrix.pool2(4, 4, (3, 3), max)
+    );
+    assert_eq!(
+        vec![vec![0]],
+        matrix.pool2(6, 6, (6, 6), |x| x.len() as i32 - 16)
+    );
+    assert_eq!(vec![vec![0]], matrix.pool2(2, 2, (2, 2), |_| 0));
+    assert!(matrix.pool2(4, 4, (0, 2), max).is_empty());
+
+    let sparse = std::collections::HashMap::from_iter([((0, 0), 7), ((3, 3), 9)]);
+    assert_eq!(
+        vec![vec![7, 0], vec![0, 9]],
+        sparse.pool2(4, 4, (2, 2), max)
+    );
+}
+
+#[test]
+fn pool2_oversized_window() {
+    let image = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+    let max = |x: &[i32]| x.iter().copied().max().unwrap_or(0);
+
+    assert_eq!(vec![vec![9]], image.pool2(3, 3, (1000, 1000), max));
+    assert_eq!(
+        vec![vec![9]],
+        image.pool2(3, 3, (1000, 1000), |x| x.len() as i32)
+    );
+    assert_eq!(vec![vec![8, 9]], image.pool2(3, 3, (usize::MAX, 2), max));
+    assert_eq!(
+        vec![vec![3], vec![6], vec![9]],
+        image.pool2(3, 3, (1, usize::MAX), max)
+    );
+}
+
+#[test]
+fn pool2_sparse_large_window() {
+    let sparse = std::collections::HashMap::from_iter([((0, 0), 1i64), ((999, 999), 41)]);
+
+    let pooled = sparse.pool2(1_000, 1_000, (1_000, 1_000), |x| {
+        x.len() as i64 * 100 + x.iter().sum::<i64>()
+    });
+    assert_eq!(vec![vec![242]], pooled);
+
+    let pooled = sparse.pool2(1_000, 1_000, (500, 1_000), |x| x.iter().sum());
+    assert_eq!(vec![vec![1], vec![41]], pooled);
+}
+
+// ref_iter_over across rows
+#[test]
+fn ref_iter_over_scattered_across_rows() {
+    #[derive(Debug, PartialEq)]
+    struct Cell(String);
+
+    let jagged: Vec<Vec<Cell>> = (0..4)
+        .map(|i| (0..=i).map(|j| Cell(format!("{}{}", i, j))).collect())
+        .collect();
+
+    let scattered = [
+        (3, 2),
+        (0, 0),
+        (2, 1),
+        (0, 1),
+        (3, 3),
+        (1, 0),
+        (9, 9),
+        (3, 0),
+    ];
+
+    let refs: Vec<&Cell> = {
+        let iter = jagged.ref_iter_over(scattered.iter().copied());
+        iter.flatten().collect()
+    };
+
+    // used after the iterator is dropped
+    let labels: Vec<_> = refs.iter().map(|x| x.0.as_str()).collect();
+    assert_eq!(vec!["32", "00", "21", "33", "10", "30"], labels);
+    for (cell, (i, j)) in refs
+        .iter()
+        .zip([(3, 2), (0, 0), (2, 1), (3, 3), (1, 0), (3, 0)])
+    {
+        assert!(std::ptr::eq(*cell, &jagged[i][j]));
+    }
+
+    let options: Vec<Option<&Cell>> = jagged.ref_iter_over(scattered.iter().copied()).collect();
+    assert_eq!(None, options[3]);
+    assert_eq!(None, options[6]);
+    assert_eq!(Some(&Cell(String::from("33"))), options[4]);
+}
+
+// index extent
+#[test]
+fn index_extent_of_maps_and_vecs() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let sparse = BTreeMap::from_iter([((4, 9), 1), ((2, 11), 2), ((7, 3), 3)]);
+    let (min, max) = sparse.index_extent().expect("non-empty");
+    assert_eq!(([2, 3], [7, 11]), (min, max));
+    let tight = (min[0]..=max[0]).flat_map(|i| (min[1]..=max[1]).map(move |j| (i, j)));
+    assert_eq!(6, sparse.iter_over(tight).flatten().sum::<i32>());
+
+    let sparse_1d = BTreeMap::from_iter([(40, 'a'), (12, 'b')]);
+    assert_eq!(Some(([12], [40])), sparse_1d.index_extent());
+
+    let hashmap: HashMap<[usize; 2], i32> = HashMap::from_iter([([1, 1], 0), ([0, 5], 0)]);
+    assert_eq!(Some(([0, 1], [1, 5])), hashmap.index_extent());
+
+    let empty: BTreeMap<(usize, usize), i32> = BTreeMap::new();
+    assert_eq!(None, empty.index_extent());
+
+    let jagged = vec![vec![], vec![1, 2, 3], vec![4], vec![]];
+    assert_eq!(Some(([1, 0], [2, 2])), jagged.index_extent());
+
+    let arr = [0; 3];
+    assert_eq!(Some(([0], [2])), arr.index_extent());
+}
+
+// gather2
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_gather2() {
+    use ndarray::Array2;
+
+    let arr = Array2::from_shape_fn((4, 5), |(i, j)| (10 * i + j) as i32);
+    let indices = [
+        (3, 4),
+        (0, 0),
+        (4, 0),
+        (2, 5),
+        (1, 3),
+        (1, 3),
+        (usize::MAX, 0),
+    ];
+
+    let gathered = arr.gather2(&indices);
+    let expected: Vec<_> = indices.iter().map(|ij| arr.at(*ij)).collect();
+    assert_eq!(expected, gathered);
+    assert_eq!(
+        vec![Some(34), Some(0), None, None, Some(13), Some(13), None],
+        gathered
+    );
+
+    assert_eq!(gathered, arr.view().gather2(&indices));
+    assert_eq!(gathered, arr.t().t().gather2(&indices));
+    assert!(arr.gather2(&[]).is_empty());
+}
+
+// banded
+#[test]
+fn banded_tridiagonal() {
+    let n = 5;
+    let matrix = Banded::new(vec![
+        (0, vec![4.0; n]),
+        (1, vec![-1.0; n - 1]),
+        (-1, vec![-2.0; n - 1]),
+    ]);
+
+    for i in 0..n + 1 {
+        for j in 0..n + 1 {
+            let expected = match (i < n && j < n, j as isize - i as isize) {
+                (true, 0) => Some(4.0),
+                (true, 1) => Some(-1.0),
+                (true, -1) => Some(-2.0),
+                _ => None,
+            };
+            assert_eq!(expected, matrix.at((i, j)));
+            assert_eq!(expected.as_ref(), matrix.ref_at([i, j]));
+        }
+    }
+
+    let upper = Banded::new(vec![(2, vec![10, 20])]);
+    assert_eq!(Some(10), upper.at((0, 2)));
+    assert_eq!(Some(20), upper.at((1, 3)));
+    assert_eq!(None, upper.at((2, 4)));
+    assert_eq!(None, upper.at((usize::MAX, 0)));
+}
+
+// exclude
+#[test]
+fn exclude_from_scalar() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let pruned: HashSet<[usize; 2]> = HashSet::from_iter([[0, 1], [2, 2]]);
+    let mut costs = ScalarAsVec(7).exclude(pruned);
+
+    assert_eq!(Some(7), costs.at((0, 0)));
+    assert_eq!(None, costs.at((0, 1)));
+    assert_eq!(None, costs.at([2, 2]));
+    assert_eq!(Some(&7), costs.ref_at((1, 0)));
+    assert_eq!(None, costs.ref_at((0, 1)));
+
+    costs.excluded_mut().insert([1, 0]);
+    costs.excluded_mut().remove(&[2, 2]);
+    assert_eq!(None, costs.at((1, 0)));
+    assert_eq!(Some(7), costs.at((2, 2)));
+
+    let all = (0..3).flat_map(|i| (0..3).map(move |j| (i, j)));
+    assert_eq!(7 * 7, costs.iter_over(all).flatten().sum::<i32>());
+
+    let matrix = vec![vec![1, 2], vec![3, 4]];
+    let excluded = matrix.exclude(BTreeSet::from_iter([(1, 1)]));
+    assert_eq!(Some(3), excluded.at((1, 0)));
+    assert_eq!(None, excluded.at((1, 1)));
+}
+
+// row gated
+#[test]
+fn row_gated_odd_rows() {
+    let matrix: Vec<Vec<i32>> = (0..4)
+        .map(|i| (0..3).map(|j| 10 * i + j).collect())
+        .collect();
+    let expected = matrix.clone();
+    let gated = RowGated::new(matrix, |i: usize| i.is_multiple_of(2));
+
+    for (i, row) in expected.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            match i % 2 {
+                0 => assert_eq!(Some(*value), gated.at((i, j))),
+                _ => {
+                    assert_eq!(None, gated.at((i, j)));
+                    assert_eq!(None, gated.ref_at([i, j]));
+                }
+            }
+        }
+    }
+    assert_eq!(Some(&22), gated.ref_at((2, 2)));
+    assert_eq!(None, gated.at((4, 0)));
+}
+
+// sorted sparse
+#[test]
+fn sorted_sparse_rows() {
+    let rows: Vec<Vec<(usize, i32)>> =
+        vec![vec![(0, 1), (4, 5), (9, 10)], vec![], vec![(2, 3), (3, 4)]];
+    let matrix: Vec<_> = rows.iter().map(|row| SortedSparse(row)).collect();
+
+    for (i, row) in rows.iter().enumerate() {
+        for j in 0..12 {
+            let expected = row.iter().find(|(k, _)| *k == j).map(|(_, x)| *x);
+            assert_eq!(expected, matrix.at((i, j)));
+            assert_eq!(expected.as_ref(), matrix.ref_at([i, j]));
+        }
+    }
+
+    assert_eq!(Some(10), matrix.at((0, 9)));
+    assert_eq!(None, matrix.at((1, 0)));
+    assert_eq!(Some(&4), matrix.ref_at((2, 3)));
+    assert_eq!(None, matrix.at((3, 0)));
+    let indices = [(0, 0), (0, 9), (1, 1), (2, 2), (2, 3)];
+    assert_eq!(
+        18,
+        matrix.iter_over(indices.into_iter()).flatten().sum::<i32>()
+    );
+}
+
+// parsed table
+#[test]
+fn parsed_table_lazy_integers() {
+    let csv = "1,2,3\n4,five,6\n7,8";
+    let rows: Vec<Vec<String>> = csv
+        .lines()
+        .map(|line| line.split(',').map(|x| x.to_string()).collect())
+        .collect();
+    let table = ParsedTable::new(&rows, |x| x.trim().parse::<i32>().ok());
+
+    assert_eq!(Some(1), table.at((0, 0)));
+    assert_eq!(Some(6), table.at([1, 2]));
+    assert_eq!(Some(8), table.at((2, 1)));
+
+    assert_eq!(None, table.at((1, 1)));
+    assert_eq!(None, table.at((2, 2)));
+    assert_eq!(None, table.at((3, 0)));
+
+    let indices = (0..3).flat_map(|i| (0..3).map(move |j| (i, j)));
+    assert_eq!(31, table.iter_over(indices).flatten().sum::<i32>());
+}
+
+// toroidal
+#[test]
+fn toroidal_wraps_both_dimensions() {
+    let (rows, cols) = (3, 4);
+    let matrix: Vec<Vec<i32>> = (0..rows)
+        .map(|i| (0..cols).map(|j| (10 * i + j) as i32).collect())
+        .collect();
+    let torus = Toroidal2::new(matrix.clone(), rows, cols);
+
+    assert_eq!(torus.at((0, 0)), torus.at((rows, cols)));
+    assert_eq!(Some(&0), torus.ref_at([2 * rows, 3 * cols]));
+    assert_eq!(Some(23), torus.at((rows - 1, 2 * cols - 1)));
+
+    // sum of the von Neumann neighborhood, wrapping at the edges
+    let neighbors_sum = |i: usize, j: usize| {
+        let neighbors = [(i + rows - 1, j), (i + 1, j), (i, j + cols - 1), (i, j + 1)];
+        torus
+            .iter_over(neighbors.into_iter())
+            .flatten()
+            .sum::<i32>()
+    };
+    assert_eq!(20 + 10 + 3 + 1, neighbors_sum(0, 0));
+    assert_eq!(13 + 3 + 22 + 20, neighbors_sum(2, 3));
+
+    let degenerate = Toroidal2::new(matrix, 0, cols);
+    assert_eq!(None, degenerate.at((0, 0)));
+}
+
+// row-uniform
+#[test]
+fn scalar_rows_ref_at() {
+    use std::collections::HashMap;
+
+    let rows = vec![ScalarAsVec(10), ScalarAsVec(20), ScalarAsVec(30)];
+    for (i, row) in rows.iter().enumerate() {
+        for j in [0, 1, 7, 1000, usize::MAX] {
+            let reference = rows.ref_at((i, j));
+            assert_eq!(Some(&row.0), reference);
+            assert!(reference.is_some_and(|x| std::ptr::eq(x, &row.0)));
+        }
+    }
+    assert_eq!(None, rows.ref_at((3, 0)));
+
+    let map = HashMap::from_iter([(2, ScalarAsVec(20)), (5, ScalarAsVec(50))]);
+    for (i, row) in map.iter() {
+        for j in [0, 3, usize::MAX] {
+            let reference = map.ref_at([*i, j]);
+            assert!(reference.is_some_and(|x| std::ptr::eq(x, &row.0)));
+        }
+    }
+    assert_eq!(None, map.ref_at((0, 0)));
+    assert_eq!(Some(50), map.at((5, 42)));
+}
+
+// quadtree
+#[test]
+fn quad_tree_clustered_points() {
+    use std::collections::HashMap;
+
+    let clusters = [
+        (0, 0),
+        (1000, 40),
+        (5, 70000),
+        (usize::MAX - 20, usize::MAX - 20),
+    ];
+    let points: Vec<_> = clusters
+        .iter()
+        .flat_map(|(ci, cj)| (0..6).flat_map(move |di| (0..6).map(move |dj| (ci + di, cj + dj))))
+        .collect();
+
+    let mut tree = QuadTree::new();
+    assert!(tree.is_empty());
+    for (k, ij) in points.iter().enumerate() {
+        assert_eq!(None, tree.insert(*ij, k));
+    }
+    assert_eq!(points.len(), tree.len());
+    assert_eq!(Some(0), tree.insert(points[0], 0));
+    assert_eq!(points.len(), tree.len());
+
+    let expected: HashMap<_, _> = points.iter().enumerate().map(|(k, ij)| (*ij, k)).collect();
+    for (ij, k) in &expected {
+        assert_eq!(Some(*k), tree.at(*ij));
+        assert_eq!(Some(k), tree.ref_at(*ij));
+    }
+    for ij in [
+        (6, 0),
+        (0, 6),
+        (999, 40),
+        (5, 69999),
+        (usize::MAX, usize::MAX),
+        (3, 500),
+    ] {
+        assert_eq!(None, tree.at(ij));
+    }
+
+    let region = (998..1003).flat_map(|i| (38..43).map(move |j| (i, j)));
+    let in_region: usize = region.clone().filter_map(|ij| expected.get(&ij)).sum();
+    assert_eq!(
+        in_region,
+        tree.iter_over(region.clone()).flatten().sum::<usize>()
+    );
+    assert_eq!(9, tree.iter_over(region).flatten().count());
+
+    let from_entries = QuadTree::from_entries([((3, 4), 1), ((3, 4), 2), ((4, 3), 3)]);
+    assert_eq!(2, from_entries.len());
+    assert_eq!(Some(2), from_entries.at((3, 4)));
+}
+
+// dyn funvec
+#[test]
+fn dyn_funvec_rows_downcast() {
+    use std::collections::HashMap;
+
+    let rows: Vec<Box<dyn DynFunVec<1, i32>>> = vec![
+        Box::new(vec![1, 2, 3]),
+        Box::new(HashMap::from_iter([(5, 50)])),
+        Box::new(ScalarAsVec(7)),
+        Box::new(vec![4, 5]),
+    ];
+
+    assert_eq!(Some(3), rows.at((0, 2)));
+    assert_eq!(None, rows.at((1, 2)));
+    assert_eq!(Some(50), rows.at((1, 5)));
+    assert_eq!(Some(7), rows.at((2, 1000)));
+    assert_eq!(Some([2]), rows[3].bounds());
+    assert_eq!(None, rows[2].bounds());
+
+    // special-case dense rows
+    let mut sum_dense = 0;
+    let mut num_other = 0;
+    for row in &rows {
+        match row.downcast_ref::<Vec<i32>>() {
+            Some(dense) => sum_dense += dense.iter().sum::<i32>(),
+            None => num_other += 1,
+        }
+    }
+    assert_eq!(15, sum_dense);
+    assert_eq!(2, num_other);
+
+    assert!(rows[1].downcast_ref::<HashMap<usize, i32>>().is_some());
+    assert!(rows[2].downcast_ref::<ScalarAsVec<i32>>().is_some());
+    assert!(rows[2].downcast_ref::<Vec<i32>>().is_none());
+}
+
+// tiled iteration
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_iter_tiled_visits_all_cells() {
+    use ndarray::Array2;
+
+    let (rows, cols) = (7, 5);
+    let arr = Array2::from_shape_fn((rows, cols), |(i, j)| (10 * i + j) as i32);
+
+    let mut row_major: Vec<_> = (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| (i, j)))
+        .map(|(i, j)| (i, j, arr.at((i, j))))
+        .collect();
+    row_major.sort();
+
+    for tile in [(1, 1), (2, 2), (3, 2), (7, 5), (10, 10)] {
+        let mut tiled: Vec<_> = arr.iter_tiled(rows, cols, tile).collect();
+        assert_eq!(rows * cols, tiled.len());
+        tiled.sort();
+        assert_eq!(row_major, tiled);
+    }
+
+    let first_tile: Vec<_> = arr
+        .iter_tiled(rows, cols, (2, 2))
+        .take(4)
+        .map(|(i, j, _)| (i, j))
+        .collect();
+    assert_eq!(vec![(0, 0), (0, 1), (1, 0), (1, 1)], first_tile);
+
+    let beyond: Vec<_> = arr
+        .iter_tiled(rows + 1, cols, (4, 4))
+        .filter(|x| x.2.is_none())
+        .collect();
+    assert_eq!(cols, beyond.len());
+    assert_eq!(0, arr.iter_tiled(rows, cols, (0, 2)).count());
+}
+
+// ragged offset
+#[test]
+fn ragged_offset_leading_gaps() {
+    let rows = vec![
+        (0, vec![1, 2, 3]),
+        (2, vec![4, 5]),
+        (5, vec![]),
+        (1, vec![6]),
+    ];
+    let ragged = RaggedOffset(&rows);
+
+    for (i, (start, values)) in rows.iter().enumerate() {
+        for j in 0..8 {
+            let expected = match j >= *start && j < start + values.len() {
+                true => Some(values[j - start]),
+                false => None,
+            };
+            assert_eq!(expected, ragged.at((i, j)));
+            assert_eq!(expected.as_ref(), ragged.ref_at([i, j]));
+        }
+    }
+
+    assert_eq!(None, ragged.at((1, 0)));
+    assert_eq!(None, ragged.at((1, 1)));
+    assert_eq!(Some(4), ragged.at((1, 2)));
+    assert_eq!(Some(6), ragged.at((3, 1)));
+    assert_eq!(None, ragged.at((4, 0)));
+}
+
+// dimensions
+#[test]
+fn dimensions_const() {
+    fn buffer<V: FunVec<DIM, i32>, const DIM: usize>(_: &V) -> Vec<usize> {
+        vec![0; V::DIMENSIONS]
+    }
+
+    assert_eq!(1, <Vec<i32> as FunVec<1, i32>>::DIMENSIONS);
+    assert_eq!(2, <Vec<Vec<i32>> as FunVec<2, i32>>::DIMENSIONS);
+    assert_eq!(1, <Vec<i32> as FunVecRef<1, i32>>::DIMENSIONS);
+    assert_eq!(2, <Vec<Vec<i32>> as FunVecRef<2, i32>>::DIMENSIONS);
+    assert_eq!(3, <ScalarAsVec<i32> as FunVec<3, i32>>::DIMENSIONS);
+
+    assert_eq!(2, buffer(&vec![vec![1]]).len());
+    assert_eq!(4, buffer::<_, 4>(&ScalarAsVec(1)).len());
+}
+
+// csr
+#[test]
+fn to_csr_round_trip() {
+    let matrix = vec![
+        vec![5, 0, 0, 1],
+        vec![0, 0, 0, 0],
+        vec![0, 2, 3, 0],
+        vec![4, 0, 0],
+    ];
+
+    let (row_pointers, column_indices, values) = matrix.to_csr(4, 4, |x| *x == 0);
+    assert_eq!(vec![0, 2, 2, 4, 5], row_pointers);
+    assert_eq!(vec![0, 3, 1, 2, 0], column_indices);
+    assert_eq!(vec![5, 1, 2, 3, 4], values);
+
+    let csr = Csr::new(&row_pointers, &column_indices, &values);
+    for i in 0..5 {
+        for j in 0..5 {
+            let expected = matrix.at((i, j)).filter(|x| *x != 0);
+            assert_eq!(expected, csr.at((i, j)));
+            assert_eq!(expected.as_ref(), csr.ref_at([i, j]));
+        }
+    }
+
+    let (row_pointers, column_indices, values) = matrix.to_csr(2, 2, |_| false);
+    assert_eq!(vec![0, 2, 4], row_pointers);
+    assert_eq!(vec![0, 1, 0, 1], column_indices);
+    assert_eq!(vec![5, 0, 0, 0], values);
+
+    let (row_pointers, _, values) = matrix.to_csr(0, 4, |x| *x == 0);
+    assert_eq!(vec![0], row_pointers);
+    assert!(values.is_empty());
+}
+
+// ndarray to csr
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_to_csr() {
+    use ndarray::Array2;
+
+    let arr = Array2::from_shape_fn((3, 4), |(i, j)| if (i + j) % 3 == 0 { 1.5 } else { 0.0 });
+    let (row_pointers, column_indices, values) = arr.to_csr(3, 4, |x| *x == 0.0);
+    assert_eq!(4, values.len());
+
+    let csr = Csr::new(&row_pointers, &column_indices, &values);
+    for i in 0..3 {
+        for j in 0..4 {
+            let expected = arr.at((i, j)).filter(|x| *x != 0.0);
+            assert_eq!(expected.map(f64::to_bits), csr.at((i, j)).map(f64::to_bits));
+        }
+    }
+}
+
+// sparse rows
+#[test]
+fn sparse_rows_override_over_default() {
+    use std::collections::HashMap;
+
+    let default = vec![0, 1, 2];
+    let overrides = HashMap::from_iter([(3, vec![30, 31])]);
+    let mut matrix = SparseRows::new(overrides, default);
+
+    for i in [0, 1, 2, 4, 100] {
+        for j in 0..3 {
+            assert_eq!(Some(j as i32), matrix.at((i, j)));
+            assert_eq!(Some(&(j as i32)), matrix.ref_at([i, j]));
+        }
+        assert_eq!(None, matrix.at((i, 3)));
+    }
+
+    assert_eq!(Some(30), matrix.at((3, 0)));
+    assert_eq!(Some(&31), matrix.ref_at((3, 1)));
+    assert_eq!(None, matrix.at((3, 2)));
+
+    matrix.overrides_mut().insert(0, vec![7; 5]);
+    assert_eq!(Some(7), matrix.at((0, 4)));
+    assert_eq!(Some(1), matrix.at((1, 1)));
+}
+
+// lru memoization
+#[cfg(any(feature = "impl_all", feature = "impl_lru"))]
+#[test]
+fn lru_memoized_evicts_least_recently_used() {
+    use std::{cell::Cell, num::NonZeroUsize, rc::Rc};
+
+    let num_calls = Rc::new(Cell::new(0));
+    let probe = num_calls.clone();
+    let expensive: Box<dyn Fn((usize, usize)) -> Option<usize>> = Box::new(move |(i, j)| {
+        probe.set(probe.get() + 1);
+        match i == j {
+            true => None,
+            false => Some(10 * i + j),
+        }
+    });
+
+    let capacity = NonZeroUsize::new(2).expect("positive");
+    let memoized = LruMemoized::new(expensive, capacity);
+    assert_eq!(capacity, memoized.capacity());
+    assert!(memoized.is_empty());
+
+    assert_eq!(Some(1), memoized.at((0, 1)));
+    assert_eq!(Some(1), memoized.at((0, 1)));
+    assert_eq!(1, num_calls.get());
+
+    assert_eq!(Some(10), memoized.at((1, 0)));
+    assert_eq!(Some(1), memoized.at((0, 1))); // (1, 0) is now the least recently used
+    assert_eq!(2, num_calls.get());
+    assert_eq!(2, memoized.len());
+
+    assert_eq!(Some(21), memoized.at((2, 1))); // evicts (1, 0)
+    assert_eq!(3, num_calls.get());
+    assert_eq!(Some(1), memoized.at((0, 1)));
+    assert_eq!(3, num_calls.get());
+    assert_eq!(Some(10), memoized.at((1, 0)));
+    assert_eq!(4, num_calls.get());
+
+    // holes are not cached
+    assert_eq!(None, memoized.at((3, 3)));
+    assert_eq!(None, memoized.at((3, 3)));
+    assert_eq!(6, num_calls.get());
+    assert_eq!(2, memoized.len());
+
+    memoized.clear();
+    assert!(memoized.is_empty());
+    assert_eq!(Some(1), memoized.at([0, 1]));
+    assert_eq!(7, num_calls.get());
+}
+
+// into ndarray
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn hashmap_to_array2_and_array1() {
+    use std::collections::HashMap;
+
+    let sparse = HashMap::from_iter([((0, 0), 3), ((1, 2), 7), ((2, 1), -1), ((9, 9), 100)]);
+    let dense = sparse.to_array2(3, 4, 0);
+    assert_eq!((3, 4), dense.dim());
+    for i in 0..3 {
+        for j in 0..4 {
+            assert_eq!(sparse.at((i, j)).unwrap_or(0), dense[[i, j]]);
+            assert_eq!(
+                sparse.at((i, j)).unwrap_or(0),
+                dense.at((i, j)).unwrap_or(-99)
+            );
+        }
+    }
+    assert_eq!(9, dense.sum());
+
+    let jagged = vec![vec![1, 2], vec![3]];
+    assert_eq!(
+        ndarray::arr2(&[[1, 2], [3, -1]]),
+        jagged.to_array2(2, 2, -1)
+    );
+
+    let row = HashMap::from_iter([(0, 1.0), (2, 3.0)]);
+    let dense = row.to_array1(4, f64::NAN);
+    assert_eq!(4, dense.len());
+    assert_eq!(Some(1.0), dense.at(0));
+    assert!(dense[1].is_nan() && dense[3].is_nan());
+    assert_eq!(Some(3.0), dense.at(2));
+}
+
+// rectangularity
+#[test]
+fn is_rectangular_rows() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let rectangular = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    assert!(rectangular.is_rectangular(3));
+    assert!(!rectangular.is_rectangular(2));
+
+    let jagged = vec![vec![1, 2, 3], vec![4, 5], vec![6, 7, 8]];
+    assert!(!jagged.is_rectangular(3));
+    assert!(!jagged.is_rectangular(2));
+
+    let empty: Vec<Vec<i32>> = vec![];
+    assert!(empty.is_rectangular(5));
+
+    let arrays = [[1, 2], [3, 4], [5, 6]];
+    assert!(arrays.is_rectangular(2));
+
+    let map = HashMap::from_iter([(0, vec![1, 2]), (7, vec![3, 4])]);
+    assert!(map.is_rectangular(2));
+    let map = BTreeMap::from_iter([(0, vec![1, 2]), (7, vec![3])]);
+    assert!(!map.is_rectangular(2));
+
+    // rows with unknown bounds
+    let sparse_rows = vec![HashMap::from_iter([(0, 1), (1, 2)])];
+    assert!(!sparse_rows.is_rectangular(2));
+}
+
+// auto traits
+fn assert_send_sync<V: Send + Sync>(_: &V) {}
+
+#[test]
+fn funvecs_are_send_sync() {
+    use std::collections::{HashMap, HashSet};
+    use std::rc::Rc;
+
+    assert_send_sync(&vec![vec![1, 2]]);
+    assert_send_sync(&HashMap::<(usize, usize), i32>::new());
+    assert_send_sync(&ScalarAsVec(1));
+    assert_send_sync(&EmptyVec::<i32>::new());
+    assert_send_sync(&EmptyVec::<Rc<i32>>::new());
+
+    let matrix = vec![vec![1, 2], vec![3, 4]];
+    let rows = vec![(0, 1), (2, 3)];
+    let pairs = [(0usize, 1), (3, 4)];
+    let pointers = [0, 1, 2];
+    let columns = [0, 1];
+    let values = [5, 6];
+
+    assert_send_sync(
+        &matrix
+            .clone()
+            .transform(|ij| ij, |x: i32| Rc::new(x).as_ref() + 1),
+    );
+    assert_send_sync(&RowGated::new(matrix.clone(), |i: usize| i > 0));
+    assert_send_sync(&Toroidal2::new(matrix.clone(), 2, 2));
+    assert_send_sync(&matrix.clone().exclude(HashSet::from_iter([(0, 0)])));
+    assert_send_sync(&ScalarAsVec(1).or(matrix.clone()));
+    assert_send_sync(&SparseRows::new(
+        HashMap::from_iter([(1, vec![1])]),
+        vec![0],
+    ));
+    assert_send_sync(&QuadTree::from_entries([((0, 0), 1)]));
+    assert_send_sync(&AutoMat::from_entries(2, 2, [((0, 0), 1)], 0.5));
+    assert_send_sync(&Coo2::new(&[(0, 0, 1)]));
+    assert_send_sync(&Csr::new(&pointers, &columns, &values));
+    assert_send_sync(&vec![SortedSparse(&pairs)]);
+    assert_send_sync(&RaggedOffset(&[(1, vec![2])]));
+    assert_send_sync(&Banded::new(vec![(0, vec![1, 2])]));
+    assert_send_sync(&OuterProduct::new(vec![1, 2], vec![3, 4]));
+    assert_send_sync(&Cyclic::new(rows.clone(), 2));
+    assert_send_sync(&ScratchFun::<_, Vec<Rc<i32>>>::new(
+        |i: usize, s: &mut Vec<Rc<i32>>| {
+            s.clear();
+            Some(i)
+        },
+    ));
+    assert_send_sync(&values.slice_vec(0..1));
+}
+
+// heterogeneous-width indices
+#[test]
+fn mixed_width_tuple_indices() {
+    let layers = vec![
+        vec![10, 11, 12],
+        vec![20, 21, 22],
+        vec![30, 31, 32],
+        vec![40, 41, 42],
+    ];
+
+    let node: u32 = 3;
+    let layer: usize = 2;
+    assert_eq!(Some(42), layers.at(UIndex((node, layer))));
+    assert_eq!(Some(42), layers.at(UIndex((3u32, 2usize))));
+    assert_eq!(Some(&21), layers.ref_at(UIndex((1usize, 1u32))));
+    assert_eq!(None, layers.at(UIndex((4u32, 0usize))));
+    assert_eq!(None, layers.at(UIndex((0usize, u32::MAX))));
+
+    assert_eq!([3, 2], UIndex((3u32, 2usize)).into_index());
+    assert_eq!([2, 3], UIndex((2usize, 3u32)).into_index());
+}
+
+// checked matrix
+#[test]
+fn checked_matrix_rejects_empty_rows() {
+    use std::collections::HashMap;
+
+    let matrix = CheckedMatrix::try_new(vec![vec![1, 2, 3], vec![4], vec![5, 6]]);
+    assert!(matrix.is_ok());
+    if let Ok(matrix) = matrix {
+        assert_eq!(Some(4), matrix.at((1, 0)));
+        assert_eq!(Some(&6), matrix.ref_at([2, 1]));
+        assert_eq!(None, matrix.at((1, 1)));
+        assert_eq!(None, matrix.at((3, 0)));
+        assert_eq!(3, matrix.rows().len());
+    }
+
+    let result = CheckedMatrix::try_new(vec![vec![1], vec![], vec![2], vec![]]);
+    let error = result.err();
+    assert_eq!(Some(MatrixError::EmptyRow { row: 1 }), error);
+    assert_eq!(
+        Some("row 1 of the matrix is empty".to_string()),
+        error.map(|e| e.to_string())
+    );
+
+    let empty: Vec<Vec<i32>> = vec![];
+    assert!(CheckedMatrix::try_new(empty).is_ok());
+
+    // rows with unknown bounds are accepted
+    let sparse_rows = vec![HashMap::<usize, i32>::new()];
+    assert!(CheckedMatrix::try_new(sparse_rows).is_ok());
+}
+
+// lazy reductions
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_sum_axis0_lazy() {
+    use ndarray::{Array2, Axis};
+
+    let arr = Array2::from_shape_fn((4, 3), |(i, j)| (i * 3 + j) as i64 - 5);
+    let reference = arr.sum_axis(Axis(0));
+
+    let column_sums = arr.view().sum_axis0(4);
+    assert_eq!(Some([3]), column_sums.bounds());
+    for j in 0..3 {
+        assert_eq!(reference.at(j), column_sums.at(j));
+    }
+    assert_eq!(None, column_sums.at(3));
+
+    let partial = arr.view().sum_axis0(2);
+    let reference = arr.slice(ndarray::s![0..2, ..]).sum_axis(Axis(0));
+    for j in 0..3 {
+        assert_eq!(reference.at(j), partial.at(j));
+    }
+}
+
+#[test]
+fn sparse_sum_axis0_lazy() {
+    use std::collections::HashMap;
+
+    let sparse = HashMap::from_iter([((0, 0), 1), ((3, 0), 2), ((1, 2), 7), ((9, 2), 100)]);
+    let column_sums = sparse.sum_axis0(5);
+    assert_eq!(5, column_sums.rows());
+    assert_eq!(None, column_sums.bounds());
+    assert_eq!(
+        vec![Some(3), None, Some(7)],
+        (0..3).map(|j| column_sums.at(j)).collect::<Vec<_>>()
+    );
+}
+
+// group rows
+#[test]
+fn group_rows_by_even_odd() {
+    use std::collections::HashMap;
+
+    let matrix = vec![
+        vec![1, 2, 3],
+        vec![10, 20, 30],
+        vec![4, 5, 6],
+        vec![40, 50],
+        vec![7, 8, 9],
+    ];
+
+    let parity = matrix.group_rows_by(0..5, 0..3, |i| i % 2 == 0, |a, b| a + b);
+    let expected = HashMap::from_iter([(true, vec![12, 15, 18]), (false, vec![50, 70, 30])]);
+    assert_eq!(expected, parity);
+
+    // restricted rows and columns
+    let parity = matrix.group_rows_by(1..4, 1..3, |i| i % 2, |a, b| a + b);
+    let expected = HashMap::from_iter([(0, vec![5, 6]), (1, vec![70, 30])]);
+    assert_eq!(expected, parity);
+
+    // sparse matrix with a cell being a hole in all rows of a group
+    let sparse = HashMap::from_iter([((0, 0), 1.5), ((2, 0), 2.5), ((1, 1), 4.0)]);
+    let parity = sparse.group_rows_by(0..3, 0..2, |i| i % 2, f64::max);
+    assert_eq!(Some(&vec![2.5, 0.0]), parity.get(&0));
+    assert_eq!(Some(&vec![0.0, 4.0]), parity.get(&1));
+
+    assert!(matrix
+        .group_rows_by(2..2, 0..3, |i| i, |a, b| a + b)
+        .is_empty());
+}
+
+// dict cols
+#[test]
+fn dict_cols_row_local_columns() {
+    use std::collections::HashMap;
+
+    let rows = vec![
+        (
+            HashMap::from_iter([(0, 2), (5, 0), (9, 1)]),
+            vec![50, 90, 0],
+        ),
+        (HashMap::from_iter([(5, 1), (2, 0), (9, 7)]), vec![12, 15]),
+    ];
+    let matrix = DictCols::new(rows);
+
+    // the same logical column maps to different positions in each row
+    assert_eq!(Some(50), matrix.at((0, 5)));
+    assert_eq!(Some(15), matrix.at((1, 5)));
+    assert_eq!(Some(&15), matrix.ref_at((1, 5)));
+
+    // columns present in only one of the rows
+    assert_eq!(Some(0), matrix.at((0, 0)));
+    assert_eq!(None, matrix.at((1, 0)));
+    assert_eq!(Some(12), matrix.at((1, 2)));
+    assert_eq!(None, matrix.at((0, 2)));
+
+    // dictionary entry pointing beyond the stored elements, and missing row
+    assert_eq!(Some(90), matrix.at((0, 9)));
+    assert_eq!(None, matrix.at((1, 9)));
+    assert_eq!(None, matrix.at((2, 5)));
+
+    let column5: Vec<_> = matrix.iter_over((0..3).map(|i| (i, 5))).collect();
+    assert_eq!(vec![Some(50), Some(15), None], column5);
+    assert_eq!(2, matrix.rows().len());
+}
+
+// nalgebra
+#[cfg(any(feature = "impl_all", feature = "impl_nalgebra"))]
+#[test]
+fn nalgebra_matrices() {
+    use nalgebra::{DMatrix, SMatrix};
+
+    let nested = vec![vec![0.0, 1.0, 2.0], vec![10.0, 11.0, 12.0]];
+
+    let dynamic = DMatrix::from_fn(2, 3, |i, j| nested[i][j]);
+    assert_matches_nested(&dynamic, &nested);
+    assert_eq!(Some(&12.0), dynamic.ref_at((1, 2)));
+    assert_eq!(Some([2, 3]), FunVec::<2, f64>::bounds(&dynamic));
+    assert_eq!(None, dynamic.at([2, 0]));
+    assert_eq!(None, dynamic.at([0, 3]));
+    assert_eq!(None, dynamic.ref_at([2, 3]));
+    assert_eq!(
+        36.0,
+        dynamic
+            .iter_over((0..3).flat_map(|i| (0..4).map(move |j| (i, j))))
+            .flatten()
+            .sum::<f64>()
+    );
+
+    let fixed = SMatrix::<f64, 2, 3>::from_fn(|i, j| nested[i][j]);
+    assert_matches_nested(&fixed, &nested);
+    assert_eq!(Some(&1.0), fixed.ref_at([0, 1]));
+    assert_eq!(Some([2, 3]), FunVec::<2, f64>::bounds(&fixed));
+    assert_eq!(None, fixed.at((2, 2)));
+    assert_eq!(None, fixed.ref_at((1, 3)));
+
+    let costs = SMatrix::<i32, 2, 2>::new(1, 2, 3, 4);
+    assert_eq!(Some(2), costs.at((0, 1)));
+    assert_eq!(Some(3), costs.at((1, 0)));
+}
+
+// strict
+#[test]
+fn strict_jagged_errors() {
+    use std::collections::HashMap;
+
+    let jagged = Strict::new(vec![vec![1, 2, 3], vec![], vec![4, 5]]);
+
+    assert_eq!(Ok(Some(3)), jagged.try_at((0, 2)));
+    assert_eq!(Ok(Some(5)), jagged.try_at([2, 1]));
+
+    // in-row out-of-column
+    assert_eq!(
+        Err(StrictError::OutOfColumn {
+            row: 0,
+            col: 3,
+            row_len: 3
+        }),
+        jagged.try_at((0, 3))
+    );
+    assert_eq!(
+        Err(StrictError::OutOfColumn {
+            row: 1,
+            col: 0,
+            row_len: 0
+        }),
+        jagged.try_at((1, 0))
+    );
+
+    // missing row
+    let missing = jagged.try_at((3, 0));
+    assert_eq!(
+        Err(StrictError::MissingRow {
+            row: 3,
+            num_rows: 3
+        }),
+        missing
+    );
+    assert_ne!(missing, jagged.try_at((2, 2)));
+    assert_eq!(
+        Some("row 3 is missing in the matrix with 3 rows".to_string()),
+        missing.err().map(|e| e.to_string())
+    );
+
+    // lenient access
+    assert_eq!(None, jagged.at((0, 3)));
+    assert_eq!(None, jagged.at((3, 0)));
+    assert_eq!(Some(&4), jagged.ref_at((2, 0)));
+
+    // rows with unknown bounds only report holes
+    let sparse = Strict::new(vec![HashMap::from_iter([(5, 1.5)])]);
+    assert_eq!(Ok(Some(1.5)), sparse.try_at((0, 5)));
+    assert_eq!(Ok(None), sparse.try_at((0, 100)));
+    assert!(matches!(
+        sparse.try_at((1, 5)),
+        Err(StrictError::MissingRow { .. })
+    ));
+}
@@ -0,0 +1,119 @@
+// ndarray
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_huge_indices() {
+    use ndarray::Array3;
+    use orx_funvec::*;
+
+    let arr = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| i + j + k);
+    let m = usize::MAX;
+    for index in [(m, 0, 0), (0, m, 0), (0, 0, m), (m, m, m)] {
+        assert_eq!(None, arr.at(index));
+        assert_eq!(None, arr.ref_at(index));
+        assert_eq!(None, arr.view().at(index));
+        assert_eq!(None, arr.view().ref_at(index));
+    }
+    assert_eq!(Some(6), arr.at((1, 2, 3)));
+}
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn vec_of_ndarray_time_slices() {
+    use ndarray::Array2;
+    use orx_funvec::*;
+
+    let slices: Vec<Array2<f32>> = (0..3)
+        .map(|t| Array2::from_shape_fn((2, 4), |(i, j)| (100 * t + 10 * i + j) as f32))
+        .collect();
+
+    assert_eq!(Some(213.0), slices.at((2, 1, 3)));
+    assert_eq!(Some(&102.0), slices.ref_at([1, 0, 2]));
+    assert_eq!(None, slices.at((3, 0, 0)));
+    assert_eq!(None, slices.at((0, 2, 0)));
+    assert_eq!(None, slices.ref_at((0, 0, 4)));
+
+    let all = (0..4).flat_map(|t| (0..3).flat_map(move |i| (0..5).map(move |j| (t, i, j))));
+    let present: Vec<f32> = slices.iter_over(all).flatten().collect();
+    assert_eq!(24, present.len());
+    let expected: f32 = slices.iter().map(|slice| slice.sum()).sum();
+    assert_eq!(expected.to_bits(), present.iter().sum::<f32>().to_bits());
+
+    let views: Vec<_> = slices.iter().map(|slice| slice.view()).collect();
+    assert_eq!(Some(13.0), views.at([0, 1, 3]));
+
+    fn first_cell<V: FunVec<3, f32>>(field: &V, t: usize) -> Option<f32> {
+        field.at((t, 0, 0))
+    }
+    assert_eq!(Some(200.0), first_cell(&slices, 2));
+}
+
+// nested arrays
+#[test]
+fn nested_arrays() {
+    use orx_funvec::*;
+
+    let arr: [[[i32; 4]; 3]; 2] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| core::array::from_fn(|k| (100 * i + 10 * j + k) as i32))
+    });
+
+    assert_eq!(Some(123), arr.at((1, 2, 3)));
+    assert_eq!(Some(&12), arr.ref_at([0, 1, 2]));
+    assert_eq!(None, arr.at((2, 0, 0)));
+    assert_eq!(None, arr.at((0, 3, 0)));
+    assert_eq!(None, arr.ref_at((0, 0, 4)));
+
+    let all = (0..3).flat_map(|i| (0..4).flat_map(move |j| (0..5).map(move |k| (i, j, k))));
+    assert_eq!(24, arr.iter_over(all).flatten().count());
+
+    assert_eq!(arr.iter().flatten().flatten().sum::<i32>(), sum3(&arr));
+}
+
+fn sum3<V: orx_funvec::FunVec<3, i32>>(vec: &V) -> i32 {
+    let all = (0..2).flat_map(|i| (0..3).flat_map(move |j| (0..4).map(move |k| [i, j, k])));
+    vec.iter_over(all).flatten().sum()
+}
+
+// permute
+#[test]
+fn permute3_reverses_axes() {
+    use orx_funvec::*;
+
+    let (n0, n1, n2) = (2, 3, 4);
+    let tensor: Vec<Vec<Vec<i32>>> = (0..n0)
+        .map(|i| {
+            (0..n1)
+                .map(|j| (0..n2).map(|k| (100 * i + 10 * j + k) as i32).collect())
+                .collect()
+        })
+        .collect();
+
+    let permuted = Permute3::new(tensor.clone(), [2, 1, 0]).expect("permutation");
+    for k in 0..n2 + 1 {
+        for j in 0..n1 + 1 {
+            for i in 0..n0 + 1 {
+                let expected = tensor.get(i).and_then(|x| x.get(j)).and_then(|x| x.get(k));
+                assert_eq!(expected.copied(), permuted.at((k, j, i)));
+                assert_eq!(expected, permuted.ref_at([k, j, i]));
+            }
+        }
+    }
+
+    let rotated = Permute3::new(tensor.clone(), [1, 2, 0]).expect("permutation");
+    assert_eq!(Some(123), rotated.at((3, 1, 2)));
+
+    assert!(Permute3::new(tensor.clone(), [0, 1, 1]).is_none());
+    assert!(Permute3::new(tensor, [0, 1, 3]).is_none());
+}
+
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn permute3_bounds() {
+    use ndarray::Array3;
+    use orx_funvec::*;
+
+    let arr = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| i + j + k);
+    let permuted = Permute3::new(arr.view(), [2, 0, 1]).expect("permutation");
+    assert_eq!(Some([3, 4, 2]), permuted.bounds());
+    assert_eq!(arr.at((1, 2, 3)), permuted.at((2, 3, 1)));
+    assert_eq!(None, permuted.at((1, 2, 3)));
+}
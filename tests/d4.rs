@@ -0,0 +1,53 @@
+// ndarray
+#[cfg(any(feature = "impl_all", feature = "impl_ndarray"))]
+#[test]
+fn ndarray_huge_indices() {
+    use ndarray::Array4;
+    use orx_funvec::*;
+
+    let arr = Array4::from_shape_fn((2, 3, 4, 5), |(i, j, k, l)| i + j + k + l);
+    let m = usize::MAX;
+    for index in [
+        (m, 0, 0, 0),
+        (0, m, 0, 0),
+        (0, 0, m, 0),
+        (0, 0, 0, m),
+        (m, m, m, m),
+    ] {
+        assert_eq!(None, arr.at(index));
+        assert_eq!(None, arr.ref_at(index));
+        assert_eq!(None, arr.view().at(index));
+        assert_eq!(None, arr.view().ref_at(index));
+    }
+    assert_eq!(Some(10), arr.at((1, 2, 3, 4)));
+}
+
+// nested arrays
+#[test]
+fn nested_arrays() {
+    use orx_funvec::*;
+
+    let arr: [[[[usize; 2]; 3]; 2]; 2] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            core::array::from_fn(|k| core::array::from_fn(|l| 1000 * i + 100 * j + 10 * k + l))
+        })
+    });
+
+    assert_eq!(Some(1121), arr.at((1, 1, 2, 1)));
+    assert_eq!(Some(&21), arr.ref_at([0, 0, 2, 1]));
+    assert_eq!(None, arr.at((2, 0, 0, 0)));
+    assert_eq!(None, arr.at((0, 2, 0, 0)));
+    assert_eq!(None, arr.at((0, 0, 3, 0)));
+    assert_eq!(None, arr.ref_at((0, 0, 0, 2)));
+
+    let all = (0..2).flat_map(|i| {
+        (0..2).flat_map(move |j| (0..3).flat_map(move |k| (0..2).map(move |l| [i, j, k, l])))
+    });
+    assert_eq!(
+        arr.iter().flatten().flatten().flatten().sum::<usize>(),
+        arr.iter_over(all).flatten().sum::<usize>()
+    );
+
+    let rows_of_arrays: Vec<[[[usize; 2]; 3]; 2]> = arr.to_vec();
+    assert_eq!(arr.at((1, 0, 1, 1)), rows_of_arrays.at((1, 0, 1, 1)));
+}
@@ -232,3 +232,72 @@ fn shortest_num_edges() {
     assert_eq!((N * N) as i32, result.sum_costs);
     assert_eq!((N * (N - 1)) as i32, result.sum_capacities);
 }
+
+#[test]
+fn retain_positive_demands() {
+    let supplies = [(0, 10), (2, 5)];
+    let sinks = [(1, -12), (3, -3)];
+
+    // demands vector as a no-box orx_closure::Closure
+    let demands = Capture((supplies, sinks)).fun(|(sup, snk), i: usize| {
+        sup.iter()
+            .chain(snk.iter())
+            .find(|(node, _)| *node == i)
+            .map(|(_, d)| *d)
+    });
+
+    let positive = demands.retain_over(0..N + 2, |d| *d > 0);
+    assert_eq!(HashMap::from_iter([([0], 10), ([2], 5)]), positive);
+
+    let none = demands.retain_over(0..N, |d| *d > 100);
+    assert!(none.is_empty());
+
+    // retained demands match the positive demands summed by the solver
+    let capacities = ScalarAsVec(1);
+    let solver = FakeMcnfSolver::new(demands, ScalarAsVec(1), capacities);
+    let result = solver.fake_solve();
+    assert_eq!(result.sum_demands, positive.values().sum::<i32>());
+}
+
+#[test]
+#[cfg(any(feature = "impl_all", feature = "impl_rstar"))]
+fn shortest_distance_rtree() {
+    use orx_funvec::{IdPoint, RTreeDistances};
+    use rstar::RTree;
+
+    let locations = [(0.0, 3.0), (3.0, 5.0), (7.0, 2.0), (1.0, 1.0)];
+
+    // costs computed as Euclidean distances of points indexed in an R-tree
+    let tree = RTree::bulk_load(
+        locations
+            .iter()
+            .enumerate()
+            .map(|(id, (x, y))| IdPoint::new([*x, *y], id))
+            .collect(),
+    );
+    let distances = RTreeDistances::new(&tree);
+
+    for (i, l1) in locations.iter().enumerate() {
+        for (j, l2) in locations.iter().enumerate() {
+            let expected = get_euclidean_distance(*l1, *l2);
+            assert_eq!(Some(expected), distances.at((i, j)).map(|d| d as i32));
+        }
+    }
+    assert_eq!(Some(4.0), distances.at((0, 1)).map(|d| d.round()));
+    assert_eq!(None, distances.at((0, N)));
+    assert_eq!(None, distances.at((N, 0)));
+    assert_eq!(Some(3), distances.nearest(0));
+    assert_eq!(None, distances.nearest(N));
+
+    let demands = Capture(()).fun(|_, i: usize| match i {
+        3 => Some(1),
+        1 => Some(-1),
+        _ => None,
+    });
+    let costs = distances.transform(|ij| ij, |d: f64| d as i32);
+    let solver = FakeMcnfSolver::new(demands, costs, ScalarAsVec(1));
+    let result = solver.fake_solve();
+
+    assert_eq!(1, result.sum_demands);
+    assert_eq!(54, result.sum_costs);
+}